@@ -0,0 +1,114 @@
+//! batch.rs
+//!
+//! Renders the runs of a solver on every input of a team
+//!
+
+use std::{error::Error, path::PathBuf};
+
+use aoc_2024::{
+    get_solver,
+    utils::{active_profile, params_for, run_inputs, Answer, AoCError, InputRun, PartRun},
+};
+use colored::Colorize;
+
+use super::{AOC_GRAY, AOC_SILVER, AOC_YELLOW};
+
+/// Runs the solver of the given day on every input of its input directory
+pub fn batch_day(
+    year: u16,
+    day: u8,
+    dir: Option<PathBuf>,
+    record: bool,
+) -> Result<(), Box<dyn Error>> {
+    let params = params_for(year, day)?;
+    let Some(solver) = get_solver(year, day, &params)? else {
+        println!("({}) Day not solved yet!", "*".custom_color(AOC_GRAY));
+        println!();
+        return Ok(());
+    };
+
+    let dir = dir.unwrap_or_else(|| active_profile().input_dir(year, day));
+    if !dir.is_dir() {
+        println!(
+            "Place the inputs of day {day} as `{{name}}.txt` files under `{}`",
+            dir.display()
+        );
+        return Err(AoCError::NoInput.into());
+    }
+
+    let runs = run_inputs(solver.as_ref(), solver.parts(), &dir, record)?;
+    if runs.is_empty() {
+        println!("Did not find any input under `{}`", dir.display());
+        return Err(AoCError::NoInput.into());
+    }
+    display_input_runs(&runs);
+    println!();
+    Ok(())
+}
+
+pub fn display_input_runs(runs: &[InputRun]) {
+    display_runs(runs);
+
+    let disagreements = runs
+        .iter()
+        .filter(|run| run.parts.iter().any(PartRun::disagrees))
+        .count();
+    if disagreements > 0 {
+        println!();
+        println!(
+            "{} of {} inputs disagree with their recorded answers",
+            disagreements,
+            runs.len()
+        );
+    }
+}
+
+/// Renders one line per run with the answers and timings of its parts
+pub fn display_runs(runs: &[InputRun]) {
+    let width = runs.iter().map(|run| run.name.len()).max().unwrap_or(0);
+    for run in runs {
+        let parts: Vec<String> = run
+            .parts
+            .iter()
+            .map(|part| match &part.answer {
+                Err(e) => format!(
+                    "Part {}: {}",
+                    part.part,
+                    e.to_string().custom_color(AOC_SILVER)
+                ),
+                Ok(Answer::Unsolved) => format!(
+                    "Part {}: {}",
+                    part.part,
+                    "Not solved yet".custom_color(AOC_GRAY)
+                ),
+                Ok(answer) => {
+                    let mut res = format!(
+                        "Part {}: {} ({} ms)",
+                        part.part,
+                        answer.to_string().custom_color(AOC_YELLOW),
+                        (part.micros as f64) / 1000.0
+                    );
+                    if part.disagrees() {
+                        res += &format!(
+                            " {}",
+                            format!("!= {}", part.expected.clone().unwrap_or_default())
+                                .custom_color(AOC_SILVER)
+                        );
+                    }
+                    res
+                }
+            })
+            .collect();
+        let mark = match run.parts.iter().any(PartRun::disagrees) {
+            true => "!".custom_color(AOC_SILVER),
+            false => "*".custom_color(AOC_YELLOW),
+        };
+        println!(
+            "({}) {:<width$}  Parse: {} ms, {}",
+            mark,
+            run.name,
+            (run.parse_micros as f64) / 1000.0,
+            parts.join(", ")
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! compare.rs
+//!
+//! Renders the runs of every variant of a day on the same input
+//!
+
+use std::error::Error;
+
+use aoc_2024::{
+    utils::{params_for, run_variants, InputRun, Param, PartRun},
+    variants,
+};
+
+use colored::Colorize;
+
+use super::{batch::display_runs, input, AOC_GRAY};
+
+/// Runs every variant of the given day on its input
+pub async fn compare_day(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let params = params_for(year, day)?;
+    let registrations = variants(year, day);
+    if registrations.is_empty() {
+        println!("({}) Day not solved yet!", "*".custom_color(AOC_GRAY));
+        println!();
+        return Ok(());
+    }
+    let declared: Vec<Param> = registrations
+        .iter()
+        .flat_map(|r| r.params)
+        .copied()
+        .collect();
+    params.check(&declared)?;
+    let variants: Vec<_> = registrations
+        .into_iter()
+        .map(|r| (r.variant_name(), r.solver(&params)))
+        .collect();
+
+    let input = input(year, day).await?;
+    let runs = run_variants(&variants, variants[0].1.parts(), &input);
+    display_variant_runs(&runs);
+    println!();
+    Ok(())
+}
+
+pub fn display_variant_runs(runs: &[InputRun]) {
+    display_runs(runs);
+
+    let disagreements = runs
+        .iter()
+        .filter(|run| run.parts.iter().any(PartRun::disagrees))
+        .count();
+    if let (true, Some(reference)) = (disagreements > 0, runs.first()) {
+        println!();
+        println!(
+            "{} of {} variants disagree with `{}`",
+            disagreements,
+            runs.len() - 1,
+            reference.name
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! description.rs
+//!
+//! Renders AoC puzzle descriptions and the examples extracted from them
+//!
+
+use std::error::Error;
+
+use aoc_2024::utils::{extract_examples, get_description, get_puzzle_page, write_fixtures};
+use colored::Colorize;
+
+use super::{AOC_BLUE, AOC_GRAY, AOC_GREEN, AOC_YELLOW};
+
+/// Renders the puzzle description of the given day
+pub async fn show_day(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let description = get_description(year, day).await?;
+    display_markdown(&description);
+    Ok(())
+}
+
+/// Extracts the examples of the given day into test fixtures
+pub async fn extract_day(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    let page = get_puzzle_page(year, day).await?;
+    let examples = extract_examples(&page);
+    if examples.is_empty() {
+        println!("({}) No examples found", "*".custom_color(AOC_GRAY));
+        return Ok(());
+    }
+
+    let dir = write_fixtures(year, day, &examples)?;
+    for (idx, example) in examples.iter().enumerate() {
+        let answers: Vec<String> = example
+            .answers
+            .iter()
+            .map(|(part, answer)| format!("Part {}: {}", part, answer.custom_color(AOC_YELLOW)))
+            .collect();
+        println!(
+            "({}) Example {} ({} lines): {}",
+            "*".custom_color(AOC_YELLOW),
+            idx + 1,
+            example.input.lines().count(),
+            answers.join(", ")
+        );
+    }
+    println!("Fixtures written to {}", dir.display());
+    Ok(())
+}
+
+/// Renders a Markdown description produced by [`get_description`] in the AoC color palette
+pub fn display_markdown(md: &str) {
+    let mut in_code_block = false;
+    for line in md.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            println!("    {}", line.on_custom_color(AOC_BLUE));
+            continue;
+        }
+        if let Some(heading) = line.strip_prefix("## ") {
+            println!("{}", heading.custom_color(AOC_GREEN).bold());
+            continue;
+        }
+        println!("{}", render_inline(line));
+    }
+}
+
+fn render_inline(line: &str) -> String {
+    let mut out = String::new();
+    let mut rest = line;
+    while let Some(idx) = rest.find(['*', '`', '[']) {
+        out.push_str(&rest[..idx]);
+        let marker = &rest[idx..idx + 1];
+        let after = &rest[idx + 1..];
+
+        let closing = match marker {
+            "[" => after.find("](").and_then(|mid| {
+                let end = after[mid..].find(')')? + mid;
+                Some((mid, end))
+            }),
+            _ => after.find(marker).map(|end| (end, end)),
+        };
+        let Some((mid, end)) = closing else {
+            out.push_str(marker);
+            rest = after;
+            continue;
+        };
+
+        let inner = &after[..mid];
+        let styled = match marker {
+            "*" => render_inline(inner)
+                .custom_color(AOC_YELLOW)
+                .bold()
+                .to_string(),
+            "`" => inner.on_custom_color(AOC_BLUE).to_string(),
+            _ => format!(
+                "{} ({})",
+                render_inline(inner).underline(),
+                after[mid + 2..end].custom_color(AOC_GRAY)
+            ),
+        };
+        out.push_str(&styled);
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,115 @@
+//! explain.rs
+//!
+//! Sends the events traced by the solvers where `--explain` asks for them
+//!
+
+use std::{
+    error::Error,
+    fs::{File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use aoc_2024::utils::{capture, TraceEvent};
+use colored::Colorize;
+use serde::Serialize;
+
+use super::AOC_GRAY;
+
+static EXPLAIN: OnceLock<Option<Explain>> = OnceLock::new();
+
+/// Where `--explain` sends the events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Explain {
+    /// Render them below the results
+    Render,
+    /// Append them as JSON lines to the file
+    File(PathBuf),
+}
+
+/// A captured event with the step of the run it was emitted in
+#[derive(Serialize)]
+struct Traced<'a> {
+    year: u16,
+    day: u8,
+    step: &'a str,
+    #[serde(flatten)]
+    event: &'a TraceEvent,
+}
+
+/// Sets where `--explain` sends the events for the rest of the run, truncating the file
+pub fn set_explain(explain: Option<Explain>) -> Result<(), Box<dyn Error>> {
+    if let Some(Explain::File(path)) = &explain {
+        File::create(path).map_err(|e| format!("Unable to create `{}`: {e}", path.display()))?;
+    }
+    let _ = EXPLAIN.set(explain);
+    Ok(())
+}
+
+pub fn explain() -> Option<&'static Explain> {
+    EXPLAIN.get().and_then(Option::as_ref)
+}
+
+/// Runs a step of the solver, capturing its trace events when explaining
+pub fn explained<R>(step: impl FnOnce() -> R) -> (R, Vec<TraceEvent>) {
+    match explain() {
+        Some(_) => capture(step),
+        None => (step(), vec![]),
+    }
+}
+
+/// Sends the events of a step of the solver where `--explain` asks for them
+pub fn explain_events(
+    year: u16,
+    day: u8,
+    step: &str,
+    events: &[TraceEvent],
+) -> Result<(), Box<dyn Error>> {
+    match explain() {
+        Some(Explain::Render) => display_events(events),
+        Some(Explain::File(path)) => write_events(path, year, day, step, events)?,
+        None => {}
+    }
+    Ok(())
+}
+
+fn display_events(events: &[TraceEvent]) {
+    for event in events {
+        match event {
+            TraceEvent::Value { label, value } => {
+                println!("    {} {label} = {value}", "value".custom_color(AOC_GRAY));
+            }
+            TraceEvent::Contribution { item, amount } => {
+                println!("    {} {item}: {amount}", "item ".custom_color(AOC_GRAY));
+            }
+            TraceEvent::Grid { label, rows } => {
+                println!("    {} {label}", "grid ".custom_color(AOC_GRAY));
+                for row in rows {
+                    println!("      {row}");
+                }
+            }
+        }
+    }
+}
+
+/// Appends the events of a step as JSON lines
+fn write_events(
+    path: &PathBuf,
+    year: u16,
+    day: u8,
+    step: &str,
+    events: &[TraceEvent],
+) -> Result<(), Box<dyn Error>> {
+    let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+    for event in events {
+        let traced = Traced {
+            year,
+            day,
+            step,
+            event,
+        };
+        writeln!(file, "{}", serde_json::to_string(&traced)?)?;
+    }
+    Ok(())
+}
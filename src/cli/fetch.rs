@@ -0,0 +1,34 @@
+//! fetch.rs
+//!
+//! Renders the download of all missing puzzle inputs at once
+//!
+
+use std::error::Error;
+
+use aoc_2024::utils::{event, prefetch_inputs, FetchStatus};
+use colored::Colorize;
+
+use super::{AOC_GRAY, AOC_GREEN, AOC_SILVER, AOC_YELLOW};
+
+/// Downloads the input of the given day, or every unlocked input of the event
+pub async fn fetch_inputs(year: u16, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let days = match day {
+        Some(day) => day..=day,
+        None => event(year).days(),
+    };
+    let statuses = prefetch_inputs(year, days).await?;
+    display_fetch_statuses(&statuses);
+    Ok(())
+}
+
+pub fn display_fetch_statuses(statuses: &[(u8, FetchStatus)]) {
+    for (day, status) in statuses {
+        let (mark, status) = match status {
+            FetchStatus::Cached => ("*".custom_color(AOC_YELLOW), "cached".to_string()),
+            FetchStatus::Fetched => ("*".custom_color(AOC_GREEN), "fetched".to_string()),
+            FetchStatus::Locked => ("*".custom_color(AOC_GRAY), "locked".to_string()),
+            FetchStatus::Failed(e) => ("*".custom_color(AOC_SILVER), format!("failed ({e})")),
+        };
+        println!("({}) Day {:>2}: {}", mark, day, status);
+    }
+}
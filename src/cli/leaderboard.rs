@@ -0,0 +1,87 @@
+//! leaderboard.rs
+//!
+//! Renders AoC private leaderboards
+//!
+
+use std::{env, error::Error};
+
+use aoc_2024::utils::{
+    event, format_duration, get_leaderboard, AoCError, Event, Leaderboard, Member,
+};
+use colored::Colorize;
+
+use super::{AOC_GRAY, AOC_SILVER, AOC_YELLOW};
+
+/// Shows the private leaderboard with the given id, or the one of the AOC_LEADERBOARD_ID env variable
+pub async fn show_leaderboard(
+    year: u16,
+    id: Option<String>,
+    day: Option<u8>,
+) -> Result<(), Box<dyn Error>> {
+    let Some(id) = id.or_else(|| env::var("AOC_LEADERBOARD_ID").ok()) else {
+        println!("Either pass the leaderboard id or set the AOC_LEADERBOARD_ID env variable.");
+        return Err(AoCError::NoLeaderboard.into());
+    };
+
+    let leaderboard = get_leaderboard(year, &id).await?;
+    match day {
+        Some(day) => display_leaderboard_day(&leaderboard, year, day),
+        None => display_leaderboard(&leaderboard, &event(year)),
+    }
+    Ok(())
+}
+
+/// Renders the overall ranking with the stars of every member per day of the event
+pub fn display_leaderboard(leaderboard: &Leaderboard, event: &Event) {
+    let days: String = event.days().map(|d| format!("{}", d % 10)).collect();
+    println!("{:>4} {:>5} {days} Name", "", "Score");
+
+    for (rank, member) in leaderboard.ranking().into_iter().enumerate() {
+        let stars: String = event
+            .days()
+            .map(|d| match member.stars_on(d) {
+                0 => "*".custom_color(AOC_GRAY).to_string(),
+                1 => "*".custom_color(AOC_SILVER).to_string(),
+                _ => "*".custom_color(AOC_YELLOW).to_string(),
+            })
+            .collect();
+        println!(
+            "{:>4} {:>5} {} {}",
+            format!("{})", rank + 1),
+            member.local_score,
+            stars,
+            member.display_name()
+        );
+    }
+}
+
+/// Renders the part 1 and part 2 completion times of every member for the given day of the given year
+pub fn display_leaderboard_day(leaderboard: &Leaderboard, year: u16, day: u8) {
+    println!("{:>4} {:>14} {:>14} Name", "", "Part 1", "Part 2");
+
+    let mut members: Vec<&Member> = leaderboard
+        .ranking()
+        .into_iter()
+        .filter(|m| m.stars_on(day) > 0)
+        .collect();
+    members.sort_by_key(|m| {
+        let (p1, p2) = (m.solve_time(year, day, 1), m.solve_time(year, day, 2));
+        (p2.is_none(), p2, p1)
+    });
+
+    for (rank, member) in members.into_iter().enumerate() {
+        let part = |part| match member.solve_time(year, day, part) {
+            Some(t) => format!("{:>14}", format_duration(t))
+                .custom_color(AOC_YELLOW)
+                .to_string(),
+            None => format!("{:>14}", "-").custom_color(AOC_GRAY).to_string(),
+        };
+        println!(
+            "{:>4} {} {} {}",
+            format!("{})", rank + 1),
+            part(1),
+            part(2),
+            member.display_name()
+        );
+    }
+}
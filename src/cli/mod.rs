@@ -0,0 +1,421 @@
+mod batch;
+mod compare;
+mod description;
+mod explain;
+mod fetch;
+mod leaderboard;
+mod progress;
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    io::{self, Write},
+    path::Path,
+    time,
+};
+
+use aoc_2024::{
+    get_solver, registry,
+    utils::{
+        self, active_profile, answer_key, cache_key, cached_answers, convert_cache, event,
+        get_input, migrate_flat_cache, params_for, store_answers, whoami, Answer, AoCError,
+        SolveError,
+    },
+    variants,
+};
+use colored::{Colorize, CustomColor};
+
+pub use batch::batch_day;
+pub use compare::compare_day;
+pub use description::{extract_day, show_day};
+use explain::{explain, explain_events, explained};
+pub use explain::{set_explain, Explain};
+pub use fetch::fetch_inputs;
+pub use leaderboard::show_leaderboard;
+pub use progress::show_progress;
+
+const AOC_GRAY: CustomColor = CustomColor {
+    r: 105,
+    g: 105,
+    b: 105,
+};
+const AOC_BLUE: CustomColor = CustomColor {
+    r: 15,
+    g: 15,
+    b: 35,
+};
+const AOC_YELLOW: CustomColor = CustomColor {
+    r: 255,
+    g: 255,
+    b: 72,
+};
+const AOC_GREEN: CustomColor = CustomColor { r: 0, g: 176, b: 0 };
+const AOC_SILVER: CustomColor = CustomColor {
+    r: 153,
+    g: 153,
+    b: 204,
+};
+
+/// Checks that the given day is part of the event of the given year, telling its days otherwise
+pub fn check_day(year: u16, day: u8) -> Result<(), Box<dyn Error>> {
+    if let Err(e) = utils::check_day(year, day) {
+        let event = event(year);
+        println!(
+            "The {year} event runs from day {} to day {}.",
+            event.first_day, event.last_day
+        );
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+/// Returns the input of the given day like [`get_input`], telling how to provide it otherwise
+async fn input(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+    let input = get_input(year, day).await;
+    if let Some(e) = input.as_ref().err().and_then(|e| e.downcast_ref()) {
+        display_input_hint(year, day, e);
+    }
+    input
+}
+
+fn display_input_hint(year: u16, day: u8, err: &AoCError) {
+    let profile = active_profile();
+    let filename = profile.input_path(year, day);
+    match err {
+        AoCError::CorruptInput => println!(
+            "The cached input `{}` is unusable and could not be fetched again.",
+            filename.display()
+        ),
+        AoCError::MissingOfflineInput => println!(
+            "Offline mode is enabled. Place the input of day {day} under `{}` or fetch it beforehand.",
+            filename.display()
+        ),
+        AoCError::NoInput => {
+            println!("Fetching input for day {day} from AoC Website is not possible, as the {} env variable is not present. Either place it in `.env` or enable it temporarily in your shell session.", profile.session_var());
+            println!(
+                "You can also grab the input yourself and place it under `{}`",
+                filename.display()
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Solves the given day on the input of the active profile and returns the number of solved parts
+pub async fn solve_day(year: u16, day: u8) -> Result<u8, Box<dyn Error>> {
+    let params = params_for(year, day)?;
+    let Some(solver) = get_solver(year, day, &params)? else {
+        println!("({}) Day not solved yet!", "*".custom_color(AOC_GRAY));
+        println!();
+        return Ok(0);
+    };
+
+    let input = input(year, day).await?;
+
+    // Explaining needs the solver to run
+    let key = answer_key(solver.as_ref(), &params, &input);
+    if let (Some(key), None) = (&key, explain()) {
+        if let Some(answers) = cached_answers(year, day, solver.parts(), key)? {
+            for (part, answer) in (1..).zip(&answers) {
+                display_cached_result(part, answer);
+            }
+            println!();
+            return Ok(answers.len() as u8);
+        }
+    }
+
+    let timer = time::Instant::now();
+    let (parsed, events) = explained(|| solver.parse_input(&input));
+    let parse_time = timer.elapsed().as_micros();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            display_solve_error("Parse", &e);
+            explain_events(year, day, "parse", &events)?;
+            println!();
+            return Ok(0);
+        }
+    };
+    display_parse_time(parse_time);
+    explain_events(year, day, "parse", &events)?;
+
+    let mut solved = vec![];
+    for part in 1..=solver.parts() {
+        let timer = time::Instant::now();
+        let (res, events) = explained(|| match part {
+            1 => parsed.part1(),
+            _ => parsed.part2(),
+        });
+        let time = timer.elapsed().as_micros();
+
+        if let Ok(answer) = res.as_ref() {
+            if answer.is_solved() {
+                solved.push((part, answer.clone()));
+            }
+        }
+        display_part_result(part, res, time);
+        explain_events(year, day, &format!("part {part}"), &events)?;
+    }
+    if let Some(key) = &key {
+        store_answers(year, day, key, &solved)?;
+    }
+
+    println!();
+    Ok(solved.len() as u8)
+}
+
+/// Renders the stars earned by the given solved parts per day, see [`Event::stars`]
+pub fn display_stars(year: u16, solved: &BTreeMap<u8, u8>) {
+    let event = event(year);
+    println!(
+        "Stars: {}/{}",
+        event.stars(solved).to_string().custom_color(AOC_YELLOW),
+        event.max_stars()
+    );
+}
+
+pub fn migrate_cache(year: u16, from: &Path) -> Result<(), Box<dyn Error>> {
+    let cache_root = &active_profile().cache_root;
+    let moved = migrate_flat_cache(from, cache_root, year)?;
+    for (from, to) in &moved {
+        println!(
+            "({}) {} -> {}",
+            "*".custom_color(AOC_YELLOW),
+            from.display(),
+            to.display()
+        );
+    }
+    println!(
+        "Migrated {} files into `{}`",
+        moved.len(),
+        cache_root.display()
+    );
+    Ok(())
+}
+
+pub fn crypt_cache(encrypt: bool) -> Result<(), Box<dyn Error>> {
+    let Some(key) = cache_key() else {
+        println!(
+            "Set the AOC_CACHE_KEY or AOC_CACHE_KEY_FILE env variable to choose the cache key."
+        );
+        return Err(AoCError::MissingKey.into());
+    };
+    let cache_root = &active_profile().cache_root;
+    let converted = convert_cache(cache_root, key, encrypt)?;
+    for path in &converted {
+        println!("({}) {}", "*".custom_color(AOC_YELLOW), path.display());
+    }
+    println!(
+        "{} {} inputs in `{}`",
+        match encrypt {
+            true => "Encrypted",
+            false => "Decrypted",
+        },
+        converted.len(),
+        cache_root.display()
+    );
+    Ok(())
+}
+
+pub fn list_days(year: u16) -> Result<(), Box<dyn Error>> {
+    let days: Vec<_> = registry().into_iter().filter(|r| r.year == year).collect();
+    for r in &days {
+        let mut notes: Vec<String> = r
+            .params
+            .iter()
+            .map(|p| format!("{}={} (examples: {})", p.name, p.default, p.sample))
+            .collect();
+        let others: Vec<_> = variants(year, r.day)
+            .iter()
+            .skip(1)
+            .map(|v| v.variant_name())
+            .collect();
+        if !others.is_empty() {
+            notes.push(format!("variants: {}", others.join(", ")));
+        }
+        println!(
+            "({}) Day {:>2}: {}{}",
+            "*".custom_color(AOC_YELLOW),
+            r.day,
+            r.title,
+            match notes.is_empty() {
+                true => String::new(),
+                false => format!(" [{}]", notes.join(", "))
+                    .custom_color(AOC_GRAY)
+                    .to_string(),
+            }
+        );
+    }
+    println!("{} days solved", days.len());
+    Ok(())
+}
+
+pub async fn show_whoami(year: u16) -> Result<(), Box<dyn Error>> {
+    let profile = active_profile();
+    println!(
+        "Profile: {} (token from {}, day 1 input cached in `{}`)",
+        profile.display_name().custom_color(AOC_YELLOW),
+        match &profile.aocd {
+            Some(aocd) => format!(
+                "{} or aocd in `{}`",
+                profile.session_var(),
+                aocd.config.display()
+            ),
+            None => profile.session_var(),
+        },
+        profile.input_path(year, 1).display()
+    );
+    if profile.token.is_none() {
+        return Err(AoCError::NoSession.into());
+    }
+
+    let user = whoami(year).await?;
+    println!(
+        "({}) Logged in as {}",
+        "*".custom_color(AOC_YELLOW),
+        user.custom_color(AOC_GREEN)
+    );
+    Ok(())
+}
+
+/// Stores the token of the active profile into the token files of `aocd`
+pub fn login(token: &str) -> Result<(), Box<dyn Error>> {
+    let profile = active_profile();
+    let Some(aocd) = &profile.aocd else {
+        return Err(format!(
+            "Tokens can only be stored with the aocd layout, set {} in `.env` otherwise",
+            profile.session_var()
+        )
+        .into());
+    };
+    aocd.write_token(profile.name.as_deref(), token)?;
+    println!(
+        "({}) Stored the token of profile {} in `{}`",
+        "*".custom_color(AOC_YELLOW),
+        profile.display_name().custom_color(AOC_YELLOW),
+        aocd.config.display()
+    );
+    Ok(())
+}
+
+pub fn get_day(year: u16) -> Result<u8, Box<dyn Error>> {
+    print!("Enter day (leave empty to solve all days): ");
+    io::stdout().flush()?;
+    let mut day = String::new();
+    io::stdin().read_line(&mut day).unwrap();
+    day = day.replace("\n", "").replace("\r", "");
+    let day = day.parse()?;
+    check_day(year, day)?;
+    Ok(day)
+}
+
+fn display_parse_time(time: u128) {
+    println!(
+        "({}) Parse (took {} ms)",
+        "*".custom_color(AOC_GRAY),
+        (time as f64) / 1000.0
+    );
+}
+
+fn display_part_result(part: u8, part_res: Result<Answer, SolveError>, time: u128) {
+    let part_res = match part_res {
+        Ok(part_res) => part_res,
+        Err(e) => return display_solve_error(&format!("Part {part}"), &e),
+    };
+    if !part_res.is_solved() {
+        println!(
+            "({}) Part {}: Not solved yet",
+            "*".custom_color(AOC_GRAY),
+            part,
+        );
+        return;
+    }
+
+    println!(
+        "({}) Part {}: {} (took {} ms)",
+        "*".custom_color(AOC_YELLOW),
+        part,
+        part_res.to_string().custom_color(AOC_YELLOW),
+        (time as f64) / 1000.0
+    );
+}
+
+fn display_cached_result(part: u8, answer: &Answer) {
+    println!(
+        "({}) Part {}: {} ({})",
+        "*".custom_color(AOC_YELLOW),
+        part,
+        answer.to_string().custom_color(AOC_YELLOW),
+        "cached".custom_color(AOC_SILVER)
+    );
+}
+
+/// Prints the error of a solver, underlining the offending snippet of parse errors
+fn display_solve_error(label: &str, err: &SolveError) {
+    println!(
+        "({}) {}: {}",
+        "!".custom_color(AOC_SILVER),
+        label,
+        err.to_string().custom_color(AOC_SILVER)
+    );
+    let SolveError::Parse {
+        line,
+        column,
+        snippet,
+        source_line,
+        ..
+    } = err
+    else {
+        return;
+    };
+
+    let gutter = line.to_string().len();
+    println!(
+        "{:>width$} {} {}",
+        line.to_string().custom_color(AOC_GRAY),
+        "|".custom_color(AOC_GRAY),
+        source_line,
+        width = gutter + 4
+    );
+    println!(
+        "{:>width$} {} {}{}",
+        "",
+        "|".custom_color(AOC_GRAY),
+        " ".repeat(column - 1),
+        "^".repeat(snippet.chars().count().max(1))
+            .custom_color(AOC_SILVER),
+        width = gutter + 4
+    );
+}
+
+pub fn display_banner(message: &str, x_padding: usize, clear: bool) {
+    let x_padding = " ".repeat(x_padding).on_custom_color(AOC_BLUE);
+    let y_border = "*"
+        .repeat(message.len() + 4)
+        .custom_color(AOC_YELLOW)
+        .on_custom_color(AOC_BLUE);
+
+    // Clear terminal
+    if clear {
+        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    }
+    println!(
+        "{x_padding}{y_border}{x_padding}",
+        x_padding = x_padding,
+        y_border = y_border
+    );
+    println!(
+        "{x_padding}{left_border}{message}{right_border}{x_padding}",
+        x_padding = x_padding,
+        left_border = "* ".custom_color(AOC_YELLOW).on_custom_color(AOC_BLUE),
+        right_border = " *".custom_color(AOC_YELLOW).on_custom_color(AOC_BLUE),
+        message = message.custom_color(AOC_GREEN).on_custom_color(AOC_BLUE),
+    );
+    println!(
+        "{x_padding}{y_border}{x_padding}",
+        x_padding = x_padding,
+        y_border = y_border
+    );
+    println!();
+}
@@ -0,0 +1,76 @@
+//! progress.rs
+//!
+//! Renders the personal star progress next to the locally available solvers
+//!
+
+use std::error::Error;
+
+use aoc_2024::{
+    registry,
+    utils::{
+        active_profile, compare_progress, event, fetch_calendar, is_puzzle_available,
+        parse_calendar, parse_user, AoCError, DayProgress, Mismatch,
+    },
+};
+use colored::Colorize;
+
+use super::{AOC_GRAY, AOC_GREEN, AOC_SILVER, AOC_YELLOW};
+
+/// Compares the stars collected on the AoC website with the local solvers
+pub async fn show_progress(year: u16) -> Result<(), Box<dyn Error>> {
+    let profile = active_profile();
+    if profile.token.is_none() {
+        println!(
+            "Syncing the star progress requires the {} env variable.",
+            profile.session_var()
+        );
+        return Err(AoCError::NoSession.into());
+    }
+
+    let calendar = fetch_calendar(year).await?;
+    if parse_user(&calendar).is_none() {
+        return Err(AoCError::InvalidSession.into());
+    }
+    let stars = parse_calendar(&calendar);
+    let days = event(year)
+        .days()
+        .filter(|day| is_puzzle_available(year, *day));
+    let progress = compare_progress(&stars, days, |day| {
+        registry()
+            .into_iter()
+            .find(|r| r.year == year && r.day == day)
+            .map(|r| r.parts)
+    });
+    display_progress(&progress);
+    Ok(())
+}
+
+pub fn display_progress(progress: &[DayProgress]) {
+    let total: u32 = progress.iter().map(|p| u32::from(p.stars)).sum();
+    for p in progress {
+        let stars = match p.stars {
+            0 => "**".custom_color(AOC_GRAY).to_string(),
+            1 => format!(
+                "{}{}",
+                "*".custom_color(AOC_SILVER),
+                "*".custom_color(AOC_GRAY)
+            ),
+            _ => "**".custom_color(AOC_YELLOW).to_string(),
+        };
+        let code = match p.has_solver {
+            true => "solver".custom_color(AOC_GREEN),
+            false => "------".custom_color(AOC_GRAY),
+        };
+        let note = match p.mismatch {
+            Some(Mismatch::MissingCode) => "Solved on the site, but no solver found",
+            Some(Mismatch::Unsubmitted) => "Solver found, but answers were never submitted",
+            None => "",
+        };
+        println!("Day {:>2}: {} {} {}", p.day, stars, code, note);
+    }
+    println!();
+    println!(
+        "Total: {} stars",
+        total.to_string().custom_color(AOC_YELLOW)
+    );
+}
@@ -1,41 +1,120 @@
-use crate::utils::DaySolver;
-
-mod d1;
-mod d10;
-mod d11;
-mod d12;
-mod d13;
-mod d14;
-mod d15;
-mod d2;
-mod d3;
-mod d4;
-mod d5;
-mod d6;
-mod d7;
-mod d8;
-mod d9;
-
-pub fn get_solver(day: u8) -> Option<Box<dyn DaySolver>> {
-    match day {
-        1 => Some(Box::new(d1::Day1)),
-        2 => Some(Box::new(d2::Day2)),
-        3 => Some(Box::new(d3::Day3)),
-        4 => Some(Box::new(d4::Day4)),
-        5 => Some(Box::new(d5::Day5)),
-        6 => Some(Box::new(d6::Day6)),
-        7 => Some(Box::new(d7::Day7)),
-        8 => Some(Box::new(d8::Day8)),
-        9 => Some(Box::new(d9::Day9)),
-        10 => Some(Box::new(d10::Day10)),
-        11 => Some(Box::new(d11::Day11)),
-        12 => Some(Box::new(d12::Day12)),
-        13 => Some(Box::new(d13::Day13)),
-        14 => Some(Box::new(d14::Day14 {
-            width: 101,
-            height: 103,
-        })),
-        15 => Some(Box::new(d15::Day15)),
-        _ => None,
+use crate::utils::{AoCError, DaySolver, Params, Registration};
+
+mod y2024;
+
+/// Every solver registered with [`register_day!`](crate::register_day), ordered by year and day.
+/// Alternative implementations are left out, see [`variants`].
+pub fn registry() -> Vec<&'static Registration> {
+    let mut days: Vec<_> = inventory::iter::<Registration>()
+        .filter(|r| r.variant.is_none())
+        .collect();
+    days.sort_by_key(|r| (r.year, r.day));
+    days
+}
+
+/// Every implementation registered for the given day, the default one first
+pub fn variants(year: u16, day: u8) -> Vec<&'static Registration> {
+    let mut variants: Vec<_> = inventory::iter::<Registration>()
+        .filter(|r| r.year == year && r.day == day)
+        .collect();
+    variants.sort_by_key(|r| r.variant);
+    variants
+}
+
+/// The years with at least one registered solver, in ascending order
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = registry().iter().map(|r| r.year).collect();
+    years.dedup();
+    years
+}
+
+/// The solver of the given day, built with the given parameters once they are checked against the
+/// parameters it declares
+pub fn get_solver(
+    year: u16,
+    day: u8,
+    params: &Params,
+) -> Result<Option<Box<dyn DaySolver>>, AoCError> {
+    let Some(r) = registry()
+        .into_iter()
+        .find(|r| r.year == year && r.day == day)
+    else {
+        return Ok(None);
+    };
+    params.check(r.params)?;
+    Ok(Some(r.solver(params)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{event, load_fixtures};
+    use std::fs;
+
+    /// Every `y{YEAR}/d{N}.rs` file is a compiled module registering day N of YEAR exactly once
+    #[test]
+    fn registered() {
+        let days: Vec<(u16, u8)> = registry().iter().map(|r| (r.year, r.day)).collect();
+        let mut files = vec![];
+        for dir in fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/src/days")).unwrap() {
+            let dir = dir.unwrap();
+            let Some(year) = dir
+                .file_name()
+                .to_str()
+                .and_then(|n| n.strip_prefix('y')?.parse().ok())
+            else {
+                continue;
+            };
+            for file in fs::read_dir(dir.path()).unwrap() {
+                let name = file.unwrap().file_name().into_string().unwrap();
+                if let Some(day) = name
+                    .strip_prefix('d')
+                    .and_then(|n| n.strip_suffix(".rs")?.parse().ok())
+                {
+                    files.push((year, day));
+                }
+            }
+        }
+        files.sort();
+        assert_eq!(days, files);
+
+        for r in inventory::iter::<Registration>() {
+            assert_eq!(variants(r.year, r.day)[0].variant, None);
+            assert_eq!(
+                r.parts,
+                event(r.year).parts(r.day),
+                "{} day {}",
+                r.year,
+                r.day
+            );
+        }
+    }
+
+    /// Runs every solver against the examples extracted into `fixtures/`
+    #[test]
+    fn fixtures() {
+        for r in inventory::iter::<Registration>() {
+            let (year, day, variant) = (r.year, r.day, r.variant_name());
+            let solver = r.solver(&Params::sample());
+            for (name, example) in load_fixtures(year, day).unwrap() {
+                // The example as saved by a Windows editor has to give the same answers
+                let windows = format!("\u{feff}{}", example.input.replace('\n', "\r\n"));
+                for input in [&example.input, &windows] {
+                    let parsed = solver.parse_input(input).unwrap();
+                    for (part, expected) in &example.answers {
+                        let res = match part {
+                            1 => parsed.part1(),
+                            _ => parsed.part2(),
+                        }
+                        .unwrap();
+                        assert_eq!(
+                            res,
+                            expected.as_str(),
+                            "{year} day {day} ({variant}) {name} part {part}"
+                        );
+                    }
+                }
+            }
+        }
     }
 }
@@ -1,29 +1,33 @@
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{Answer, Solution, SolveError};
+
+// Copy to `y{YEAR}/dX.rs` and declare it with `mod dX;` in `y{YEAR}/mod.rs`, the registration does the rest
+register_day!(YEAR, X, "Puzzle Title", DayX);
 
 pub struct DayX;
 
-impl DaySolver for DayX {
-    fn part1(&self, input: &str) -> Option<String> {
-        None
-    }
+impl Solution for DayX {
+    type Input = String;
+    // Cached answers are keyed by the build, unless a `const VERSION: Option<u32>` is declared and
+    // bumped on every change of the answers
 
-    fn part2(&self, input: &str) -> Option<String> {
-        None
+    // The input comes without BOM, `\r` or trailing newline: set `const NORMALIZE: bool = false;`
+    // to get it verbatim
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_string())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use indoc::indoc;
+    // Explain intermediate values with `utils::trace`, e.g. `trace::value("sum", || sum);`, shown by
+    // `--explain` and free otherwise
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::Unsolved)
+    }
 
-    #[test]
-    fn sample() {
-        let input = indoc! {"
-            TODO
-        "};
-        let solver = DayX {};
-        assert_eq!(solver.part1(input).unwrap(), "TOOD");
-        assert_eq!(solver.part2(input).unwrap(), "TODO");
+    // The last day of an event has a single part: set `const PARTS: u8 = 1;` and drop `part2`
+    fn part2(&self, input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::Unsolved)
     }
 }
+
+// The examples of the puzzle are tested by the fixtures harness in `days/mod.rs`.
+// Generate them with `cargo run -- extract X --year YEAR`, which writes `fixtures/{YEAR}/dayX/`.
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use crate::register_day;
+use crate::utils::{parse_token, split_once, Answer, Solution, SolveError};
+
+register_day!(2024, 1, "Historian Hysteria", Day1);
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<isize>, Vec<isize>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut l1 = vec![];
+        let mut l2 = vec![];
+        for l in input.lines() {
+            let (n1, n2) = split_once(input, l, "   ")?;
+            l1.push(parse_token(input, n1, "a number")?);
+            l2.push(parse_token(input, n2, "a number")?);
+        }
+        Ok((l1, l2))
+    }
+
+    fn part1(&self, (l1, l2): &Self::Input) -> Result<Answer, SolveError> {
+        let mut l1 = l1.clone();
+        let mut l2 = l2.clone();
+        l1.sort();
+        l2.sort();
+        let mut sum = 0;
+        for (n1, n2) in l1.iter().zip(l2.iter()) {
+            sum += (n1 - n2).abs();
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, (l1, l2): &Self::Input) -> Result<Answer, SolveError> {
+        let (l1, l2) = (count(l1), count(l2));
+        let mut sum = 0;
+        for (n1, c1) in l1 {
+            let Some(c2) = l2.get(&n1) else {
+                continue;
+            };
+            sum += c1 * (n1 * c2);
+        }
+
+        Ok(sum.into())
+    }
+}
+
+fn count(list: &[isize]) -> HashMap<isize, isize> {
+    let mut counts = HashMap::new();
+    list.iter().for_each(|n| {
+        counts
+            .entry(*n)
+            .and_modify(|counter| *counter += 1)
+            .or_insert(1);
+    });
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            3   4
+            4   3
+            2   5
+            1   3
+            3   9
+            3   3
+        "};
+        let solver = Day1 {};
+        let input = solver.parse(input).unwrap();
+        assert_eq!(solver.part1(&input).unwrap(), "11");
+        assert_eq!(solver.part2(&input).unwrap(), "31");
+    }
+
+    #[test]
+    fn malformed() {
+        let solver = Day1 {};
+        let err = solver.parse("3   4\n4 3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected `   `, found `4 3`"
+        );
+        let err = solver.parse("3   4\n4   x\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a number, found `x`"
+        );
+    }
+}
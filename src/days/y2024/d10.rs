@@ -1,16 +1,28 @@
 use std::collections::HashSet;
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{char_tokens, grid_size, parse_token, Answer, Solution, SolveError};
+
+register_day!(2024, 10, "Hoof It", Day10);
 
 pub struct Day10;
 
-impl DaySolver for Day10 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let map: Vec<Vec<u8>> = input
+impl Solution for Day10 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        grid_size(input)?;
+        input
             .lines()
-            .map(|l| l.chars().map(|c| c.to_digit(10).unwrap() as u8).collect())
-            .collect();
+            .map(|l| {
+                char_tokens(l)
+                    .map(|(_, token)| parse_token(input, token, "a height digit"))
+                    .collect()
+            })
+            .collect()
+    }
 
+    fn part1(&self, map: &Self::Input) -> Result<Answer, SolveError> {
         let height = map.len();
         let width = map[0].len();
 
@@ -20,21 +32,16 @@ impl DaySolver for Day10 {
                 let e = map[y][x];
                 if e == 9 {
                     let mut heads = HashSet::new();
-                    get_trail_heads(&map, x, y, 9, &mut heads);
+                    get_trail_heads(map, x, y, 9, &mut heads);
                     sum += heads.len();
                 }
             }
         }
 
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let map: Vec<Vec<u8>> = input
-            .lines()
-            .map(|l| l.chars().map(|c| c.to_digit(10).unwrap() as u8).collect())
-            .collect();
-
+    fn part2(&self, map: &Self::Input) -> Result<Answer, SolveError> {
         let height = map.len();
         let width = map[0].len();
 
@@ -44,12 +51,12 @@ impl DaySolver for Day10 {
                 let e = map[y][x];
                 if e == 9 {
                     let mut visited = HashSet::new();
-                    sum += get_trail_heads2(&map, x, y, 9, &mut visited);
+                    sum += get_trail_heads2(map, x, y, 9, &mut visited);
                 }
             }
         }
 
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 }
 
@@ -129,7 +136,7 @@ mod tests {
             10456732
         "};
         let solver = Day10 {};
-        assert_eq!(solver.part1(input).unwrap(), "36");
-        assert_eq!(solver.part2(input).unwrap(), "81");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "36");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "81");
     }
 }
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use crate::register_day;
+use crate::utils::{parse_token, Answer, Param, Params, Solution, SolveError};
+
+// The stone counts overflow beyond 90 blinks
+const BLINKS1: Param = Param {
+    name: "blinks1",
+    default: 25,
+    sample: 25,
+    min: 0,
+    max: 90,
+};
+const BLINKS2: Param = Param {
+    name: "blinks2",
+    default: 75,
+    sample: 75,
+    min: 0,
+    max: 90,
+};
+
+register_day!(2024, 11, "Plutonian Pebbles", Day11::new);
+
+pub struct Day11 {
+    blinks1: usize,
+    blinks2: usize,
+}
+
+impl Day11 {
+    pub fn new(params: &Params) -> Self {
+        Self {
+            blinks1: params.value(&BLINKS1) as usize,
+            blinks2: params.value(&BLINKS2) as usize,
+        }
+    }
+}
+
+impl Solution for Day11 {
+    type Input = Vec<usize>;
+
+    const PARAMS: &'static [Param] = &[BLINKS1, BLINKS2];
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .split(" ")
+            .map(|e| parse_token(input, e, "a number"))
+            .collect()
+    }
+
+    fn part1(&self, stones: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        let mut dp = HashMap::new();
+        for &s in stones {
+            sum += explore(s, &mut dp, self.blinks1);
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(&self, stones: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        let mut dp = HashMap::new();
+        for &s in stones {
+            sum += explore(s, &mut dp, self.blinks2);
+        }
+
+        Ok(sum.into())
+    }
+}
+
+fn explore(stone: usize, dp: &mut HashMap<(usize, usize), usize>, steps_rem: usize) -> usize {
+    if steps_rem == 0 {
+        return 1;
+    }
+
+    if let Some(sum) = dp.get(&(stone, steps_rem)) {
+        return *sum;
+    }
+    let num_digits = stone.checked_ilog10().unwrap_or(0) as usize + 1;
+    let sum = match stone {
+        0 => explore(1, dp, steps_rem - 1),
+        _ if num_digits.is_multiple_of(2) => {
+            let pow = 10_i32.pow(num_digits as u32 / 2) as usize;
+            let l: usize = stone / pow;
+            let r: usize = stone % pow;
+            explore(l, dp, steps_rem - 1) + explore(r, dp, steps_rem - 1)
+        }
+        _ => explore(stone * 2024, dp, steps_rem - 1),
+    };
+    dp.insert((stone, steps_rem), sum);
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"125 17"};
+        let solver = Day11::new(&Params::sample());
+        assert_eq!(
+            solver.part1(&solver.parse(input).unwrap()).unwrap(),
+            "55312"
+        );
+    }
+}
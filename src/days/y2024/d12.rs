@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{grid_size, Answer, Solution, SolveError};
 
 enum Direction {
     Up,
@@ -44,7 +45,7 @@ impl Position {
     }
 }
 
-struct Region {
+pub struct Region {
     area: HashSet<Position>,
 }
 
@@ -105,7 +106,7 @@ impl Region {
         sum
     }
 
-    fn calc_sides(&mut self) -> usize {
+    fn calc_sides(&self) -> usize {
         let mut sides = 0;
         for pos in self.area.iter() {
             sides += self.count_outer_corners(*pos);
@@ -114,7 +115,7 @@ impl Region {
         sides
     }
 
-    fn calc_edges(&mut self) -> usize {
+    fn calc_edges(&self) -> usize {
         let mut sum = 0;
         for pos in self.area.iter() {
             if !self.contains(*pos, Direction::Right) {
@@ -134,32 +135,38 @@ impl Region {
     }
 }
 
+register_day!(2024, 12, "Garden Groups", Day12);
+
 pub struct Day12;
 
-impl DaySolver for Day12 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let mut regions = parse(input);
+impl Solution for Day12 {
+    type Input = Vec<Region>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
 
+    fn part1(&self, regions: &Self::Input) -> Result<Answer, SolveError> {
         let mut sum = 0;
-        for r in &mut regions {
+        for r in regions {
             let edges = r.calc_edges();
             sum += edges * r.area.len();
         }
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let mut regions = parse(input);
+    fn part2(&self, regions: &Self::Input) -> Result<Answer, SolveError> {
         let mut sum = 0;
-        for r in &mut regions {
+        for r in regions {
             let sides = r.calc_sides();
             sum += sides * r.area.len();
         }
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 }
 
-fn parse(input: &str) -> Vec<Region> {
+fn parse(input: &str) -> Result<Vec<Region>, SolveError> {
+    grid_size(input)?;
     let mut positions = HashMap::new();
     for (y, l) in input.lines().enumerate() {
         for (x, a) in l.chars().enumerate() {
@@ -172,7 +179,7 @@ fn parse(input: &str) -> Vec<Region> {
         let region_id = *positions.get(&pos).unwrap();
         regions.push(explore_region(&mut positions, region_id, pos));
     }
-    regions
+    Ok(regions)
 }
 
 fn explore_region(
@@ -217,7 +224,7 @@ mod tests {
             MMMISSJEEE
         "};
         let solver = Day12 {};
-        assert_eq!(solver.part1(input).unwrap(), "1930");
-        assert_eq!(solver.part2(input).unwrap(), "1206");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "1930");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "1206");
     }
 }
@@ -0,0 +1,163 @@
+use crate::register_day;
+use crate::utils::{
+    parse_token, split_once, strip_prefix, Answer, Param, Params, Solution, SolveError,
+};
+
+const OFFSET: Param = Param {
+    name: "offset",
+    default: 10000000000000,
+    sample: 10000000000000,
+    min: 0,
+    max: 1_000_000_000_000_000,
+};
+
+#[derive(Clone)]
+pub struct Machine {
+    button_a: (f64, f64),
+    button_b: (f64, f64),
+    prize: (f64, f64),
+}
+
+register_day!(2024, 13, "Claw Contraption", Day13::new);
+
+pub struct Day13 {
+    /// Added to both prize coordinates in part 2
+    offset: f64,
+}
+
+impl Day13 {
+    pub fn new(params: &Params) -> Self {
+        Self {
+            offset: params.value(&OFFSET) as f64,
+        }
+    }
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    const PARAMS: &'static [Param] = &[OFFSET];
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
+
+    fn part1(&self, machines: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+
+        for m in machines {
+            let Some((a, b)) = get_solution(m) else {
+                continue;
+            };
+            sum += a as isize * 3 + b as isize;
+        }
+
+        Ok(sum.into())
+    }
+
+    fn part2(&self, machines: &Self::Input) -> Result<Answer, SolveError> {
+        let mut machines = machines.clone();
+        for m in &mut machines {
+            m.prize.0 += self.offset;
+            m.prize.1 += self.offset;
+        }
+        let mut sum = 0;
+
+        for m in machines {
+            let Some((a, b)) = get_solution(&m) else {
+                continue;
+            };
+            sum += a as isize * 3 + b as isize;
+        }
+
+        Ok(sum.into())
+    }
+}
+
+/*
+ * Simple Linear Algebra:
+ * 2 unknowns, 2 equations -> we will always get a solution
+ * however, the solution might be negative
+ * a * a_0 + b * b_0 = p_0
+ * a * a_1 + b * b_1 = p_1
+ *
+ * a = (p_0 - b * b_0) / a_0
+ * b * b_1 - b * b_0 / a_0 * a_1 = p_1 - p_0 / a_0 * a_1
+ * b = (p_1 - p_0 / a_0 * a_1) / (b_1 - b_0 / a_0 * a_1)
+ */
+fn get_solution(m: &Machine) -> Option<(usize, usize)> {
+    let b = (m.prize.1 - m.prize.0 / m.button_a.0 * m.button_a.1)
+        / (m.button_b.1 - m.button_b.0 / m.button_a.0 * m.button_a.1);
+    let a = (m.prize.0 - b * m.button_b.0) / m.button_a.0;
+
+    if a < 0.0 || b < 0.0 {
+        return None;
+    }
+
+    let a = a.round();
+    let b = b.round();
+
+    if a * m.button_a.0 + b * m.button_b.0 != m.prize.0 {
+        return None;
+    }
+
+    if a * m.button_a.1 + b * m.button_b.1 != m.prize.1 {
+        return None;
+    }
+
+    Some((a as usize, b as usize))
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>, SolveError> {
+    input
+        .split("\n\n")
+        .map(|machine| {
+            let mut lines = machine.lines();
+            let mut next_pair = |prefix: &str, separator: &str| {
+                let line = lines.next().unwrap_or(&machine[machine.len()..]);
+                let line = strip_prefix(input, line, prefix)?;
+                let (x, y) = split_once(input, line, ", ")?;
+                let x = strip_prefix(input, x, &format!("X{separator}"))?;
+                let y = strip_prefix(input, y, &format!("Y{separator}"))?;
+                Ok::<_, SolveError>((
+                    parse_token(input, x, "a number")?,
+                    parse_token(input, y, "a number")?,
+                ))
+            };
+            Ok(Machine {
+                button_a: next_pair("Button A: ", "+")?,
+                button_b: next_pair("Button B: ", "+")?,
+                prize: next_pair("Prize: ", "=")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            Button A: X+94, Y+34
+            Button B: X+22, Y+67
+            Prize: X=8400, Y=5400
+
+            Button A: X+26, Y+66
+            Button B: X+67, Y+21
+            Prize: X=12748, Y=12176
+
+            Button A: X+17, Y+86
+            Button B: X+84, Y+37
+            Prize: X=7870, Y=6450
+
+            Button A: X+69, Y+23
+            Button B: X+27, Y+71
+            Prize: X=18641, Y=10279
+        "};
+        let solver = Day13::new(&Params::sample());
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "480");
+    }
+}
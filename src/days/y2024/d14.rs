@@ -1,27 +1,53 @@
 use std::collections::HashMap;
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{
+    parse_token, split_once, strip_prefix, Answer, Param, Params, Solution, SolveError,
+};
 
-struct Robot {
+const WIDTH: Param = Param {
+    name: "width",
+    default: 101,
+    sample: 11,
+    min: 1,
+    max: 1_000_000,
+};
+const HEIGHT: Param = Param {
+    name: "height",
+    default: 103,
+    sample: 7,
+    min: 1,
+    max: 1_000_000,
+};
+
+pub struct Robot {
     pos: (isize, isize),
     v: (isize, isize),
 }
 
+register_day!(2024, 14, "Restroom Redoubt", Day14::new);
+
 pub struct Day14 {
-    pub width: isize,
-    pub height: isize,
+    width: isize,
+    height: isize,
 }
 
-impl DaySolver for Day14 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let robots = parse(input);
+impl Solution for Day14 {
+    type Input = Vec<Robot>;
+
+    const PARAMS: &'static [Param] = &[WIDTH, HEIGHT];
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
 
+    fn part1(&self, robots: &Self::Input) -> Result<Answer, SolveError> {
         let mut upper_left = 0;
         let mut lower_right = 0;
         let mut lower_left = 0;
         let mut upper_right = 0;
 
-        for r in &robots {
+        for r in robots {
             let pos = self.get_pos(r, 100);
             if pos.0 < self.width / 2 && pos.1 < self.height / 2 {
                 upper_left += 1;
@@ -38,11 +64,10 @@ impl DaySolver for Day14 {
         }
         let safety_factor = upper_left * lower_right * lower_left * upper_right;
 
-        Some(safety_factor.to_string())
+        Ok(safety_factor.into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let robots = parse(input);
+    fn part2(&self, robots: &Self::Input) -> Result<Answer, SolveError> {
         let mut vertical_positions = HashMap::new();
         let mut horizontal_positions = HashMap::new();
         let mut seconds = -1;
@@ -53,7 +78,7 @@ impl DaySolver for Day14 {
             seconds += 1;
             horizontal_positions.clear();
             vertical_positions.clear();
-            for r in &robots {
+            for r in robots {
                 let pos = self.get_pos(r, seconds);
                 horizontal_positions
                     .entry(pos.1)
@@ -89,15 +114,24 @@ impl DaySolver for Day14 {
                 let t1 = offset_y + i * self.height;
                 let t2 = offset_x + j * self.width;
                 if t1 == t2 {
-                    return Some(t1.to_string());
+                    return Ok(t1.into());
                 }
             }
         }
-        None
+        Err(SolveError::NoSolution(
+            "the robots never line up in both directions".to_string(),
+        ))
     }
 }
 
 impl Day14 {
+    pub fn new(params: &Params) -> Self {
+        Self {
+            width: params.value(&WIDTH) as isize,
+            height: params.value(&HEIGHT) as isize,
+        }
+    }
+
     fn get_pos(&self, r: &Robot, seconds: isize) -> (isize, isize) {
         (
             (r.pos.0 + seconds * r.v.0).rem_euclid(self.width),
@@ -106,23 +140,21 @@ impl Day14 {
     }
 }
 
-fn parse(input: &str) -> Vec<Robot> {
+fn parse(input: &str) -> Result<Vec<Robot>, SolveError> {
+    let pair = |s: &str| {
+        let (x, y) = split_once(input, s, ",")?;
+        Ok::<_, SolveError>((
+            parse_token(input, x, "a number")?,
+            parse_token(input, y, "a number")?,
+        ))
+    };
     input
         .lines()
         .map(|l| {
-            let l = l.replace("p=", "").replace("v=", "");
-            let mut l = l.split(" ");
-            let mut pos = l.next().unwrap().split(",");
-            let pos = (
-                pos.next().unwrap().parse().unwrap(),
-                pos.next().unwrap().parse().unwrap(),
-            );
-            let mut v = l.next().unwrap().split(",");
-            let v = (
-                v.next().unwrap().parse().unwrap(),
-                v.next().unwrap().parse().unwrap(),
-            );
-            Robot { pos, v }
+            let (pos, v) = split_once(input, l, " ")?;
+            let pos = pair(strip_prefix(input, pos, "p=")?)?;
+            let v = pair(strip_prefix(input, v, "v=")?)?;
+            Ok(Robot { pos, v })
         })
         .collect()
 }
@@ -148,10 +180,7 @@ mod tests {
             p=2,4 v=2,-3
             p=9,5 v=-3,-3
         "};
-        let solver = Day14 {
-            width: 11,
-            height: 7,
-        };
-        assert_eq!(solver.part1(input).unwrap(), "12");
+        let solver = Day14::new(&Params::sample());
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "12");
     }
 }
@@ -1,9 +1,10 @@
 use std::{collections::HashSet, fmt::Error, str::FromStr};
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{char_tokens, parse_token, sections, Answer, Solution, SolveError};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
-enum Instruction {
+pub enum Instruction {
     Up,
     Down,
     Left,
@@ -45,13 +46,35 @@ impl Position {
     }
 }
 
-struct Warehouse {
+#[derive(Clone)]
+pub struct Warehouse {
     robot: Position,
     walls: HashSet<Position>,
     boxes: HashSet<Vec<Position>>,
 }
 
 impl Warehouse {
+    /// The warehouse of part 2, where everything except the robot is twice as wide
+    fn widen(&self) -> Self {
+        let widen = |pos: &Position| Position::new(2 * pos.x, pos.y);
+        Self {
+            robot: widen(&self.robot),
+            walls: self
+                .walls
+                .iter()
+                .flat_map(|pos| [widen(pos), widen(pos).pos_in_dir(Instruction::Right)])
+                .collect(),
+            boxes: self
+                .boxes
+                .iter()
+                .map(|positions| {
+                    let pos = widen(&positions[0]);
+                    vec![pos, pos.pos_in_dir(Instruction::Right)]
+                })
+                .collect(),
+        }
+    }
+
     fn get_box_checksum(&self) -> isize {
         let mut sum = 0;
         for box_positions in &self.boxes {
@@ -154,28 +177,36 @@ impl Warehouse {
     }
 }
 
+register_day!(2024, 15, "Warehouse Woes", Day15);
+
 pub struct Day15;
 
-impl DaySolver for Day15 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let (mut warehouse, instructions) = parse(input, false);
-        for i in instructions {
+impl Solution for Day15 {
+    type Input = (Warehouse, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
+
+    fn part1(&self, (warehouse, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        let mut warehouse = warehouse.clone();
+        for &i in instructions {
             let robot = warehouse.robot;
             execute_move(&mut warehouse, robot, i);
         }
-        Some(warehouse.get_box_checksum().to_string())
+        Ok(warehouse.get_box_checksum().into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let (mut warehouse, instructions) = parse(input, true);
-        for i in instructions {
+    fn part2(&self, (warehouse, instructions): &Self::Input) -> Result<Answer, SolveError> {
+        let mut warehouse = warehouse.widen();
+        for &i in instructions {
             let robot = warehouse.robot;
             if !discover(&warehouse, robot, i) {
                 continue;
             }
             execute_move(&mut warehouse, robot, i);
         }
-        Some(warehouse.get_box_checksum().to_string())
+        Ok(warehouse.get_box_checksum().into())
     }
 }
 
@@ -227,78 +258,43 @@ fn discover(warehouse: &Warehouse, pos: Position, instruction: Instruction) -> b
     true
 }
 
-fn parse(input: &str, part2: bool) -> (Warehouse, Vec<Instruction>) {
-    let input = input.replace("\r", "");
-    let mut input = input.split("\n\n");
-    let warehouse = input.next().unwrap();
-    let instructions = input.next().unwrap().replace("\n", "");
-    let mut robot = Position::new(-1, -1);
+fn parse(input: &str) -> Result<(Warehouse, Vec<Instruction>), SolveError> {
+    let sections = sections(input, 2)?;
+    let (warehouse, instructions) = (sections[0], sections[1]);
+    let mut robot = None;
     let mut walls = HashSet::new();
     let mut boxes = HashSet::new();
     for (y, l) in warehouse.lines().enumerate() {
-        for (x, c) in l.chars().enumerate() {
-            if !part2 {
-                parse_part1(
-                    x as isize, y as isize, c, &mut walls, &mut boxes, &mut robot,
-                );
-            } else {
-                parse_part2(
-                    x as isize, y as isize, c, &mut walls, &mut boxes, &mut robot,
-                );
+        for (x, (c, token)) in char_tokens(l).enumerate() {
+            let pos = Position::new(x as isize, y as isize);
+            match c {
+                '#' => {
+                    walls.insert(pos);
+                }
+                'O' => {
+                    boxes.insert(vec![pos]);
+                }
+                '@' if robot.is_none() => robot = Some(pos),
+                '@' => return Err(SolveError::parse(input, token, "a single robot")),
+                '.' => {}
+                _ => return Err(SolveError::parse(input, token, "one of `.#O@`")),
             }
         }
     }
+    let Some(robot) = robot else {
+        return Err(SolveError::parse(input, warehouse, "a robot `@`"));
+    };
     let warehouse = Warehouse {
         robot,
         walls,
         boxes,
     };
     let instructions = instructions
-        .chars()
-        .map(|c| Instruction::from_str(&c.to_string()).unwrap())
-        .collect();
-    (warehouse, instructions)
-}
-
-fn parse_part1(
-    x: isize,
-    y: isize,
-    c: char,
-    walls: &mut HashSet<Position>,
-    boxes: &mut HashSet<Vec<Position>>,
-    robot: &mut Position,
-) {
-    match c {
-        '#' => {
-            walls.insert(Position::new(x, y));
-        }
-        'O' => {
-            boxes.insert(vec![Position::new(x, y)]);
-        }
-        '@' => *robot = Position::new(x, y),
-        _ => {}
-    }
-}
-
-fn parse_part2(
-    x: isize,
-    y: isize,
-    c: char,
-    walls: &mut HashSet<Position>,
-    boxes: &mut HashSet<Vec<Position>>,
-    robot: &mut Position,
-) {
-    match c {
-        '#' => {
-            walls.insert(Position::new(2 * x, y));
-            walls.insert(Position::new(2 * x + 1, y));
-        }
-        'O' => {
-            boxes.insert(vec![Position::new(2 * x, y), Position::new(2 * x + 1, y)]);
-        }
-        '@' => *robot = Position::new(2 * x, y),
-        _ => {}
-    }
+        .lines()
+        .flat_map(char_tokens)
+        .map(|(_, token)| parse_token(input, token, "one of `<>^v`"))
+        .collect::<Result<_, _>>()?;
+    Ok((warehouse, instructions))
 }
 
 #[cfg(test)]
@@ -332,7 +328,10 @@ mod tests {
             v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
         "};
         let solver = Day15 {};
-        assert_eq!(solver.part1(input).unwrap(), "10092");
-        assert_eq!(solver.part2(input).unwrap(), "9021");
+        assert_eq!(
+            solver.part1(&solver.parse(input).unwrap()).unwrap(),
+            "10092"
+        );
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "9021");
     }
 }
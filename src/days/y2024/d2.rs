@@ -0,0 +1,104 @@
+use crate::register_day;
+use crate::utils::{parse_token, Answer, Solution, SolveError};
+
+const REPORT_DELIMITER: &str = " ";
+const REPORT_MAX_DISTANCE: isize = 3;
+
+register_day!(2024, 2, "Red-Nosed Reports", Day2);
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .map(|report| {
+                report
+                    .split(REPORT_DELIMITER)
+                    .map(|n| parse_token(input, n, "a number"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(&self, reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(reports
+            .iter()
+            .fold(0, |acc, report| acc + is_report_safe(report, None) as usize)
+            .into())
+    }
+
+    fn part2(&self, reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(reports
+            .iter()
+            .fold(0, |acc, report| {
+                for i in 0..report.len() {
+                    if is_report_safe(report, Some(i)) {
+                        return acc + 1;
+                    }
+                }
+                acc
+            })
+            .into())
+    }
+}
+
+fn is_report_safe(report: &[isize], skip: Option<usize>) -> bool {
+    let mut prev_opt = None;
+    let mut ascending_opt = None;
+    for (i, &n) in report.iter().enumerate() {
+        if let Some(skip) = skip {
+            if i == skip {
+                continue;
+            }
+        }
+
+        let Some(prev) = prev_opt else {
+            // first element, no previous element
+            prev_opt = Some(n);
+            continue;
+        };
+        prev_opt = Some(n);
+
+        if (n - prev).abs() > REPORT_MAX_DISTANCE || n == prev {
+            return false;
+        }
+
+        let Some(ascending) = ascending_opt else {
+            ascending_opt = Some(prev < n);
+            continue;
+        };
+
+        if ascending && prev >= n {
+            return false;
+        }
+        if !ascending && prev <= n {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            7 6 4 2 1
+            1 2 7 8 9
+            9 7 6 2 1
+            1 3 2 4 5
+            8 6 4 4 1
+            1 3 6 7 9
+        "};
+        let solver = Day2 {};
+        let input = solver.parse(input).unwrap();
+        assert_eq!(solver.part1(&input).unwrap(), "2");
+        assert_eq!(solver.part2(&input).unwrap(), "4");
+    }
+}
@@ -0,0 +1,82 @@
+use regex::Regex;
+
+use crate::register_day;
+use crate::utils::{parse_token, Answer, Solution, SolveError};
+
+register_day!(2024, 3, "Mull It Over", Day3);
+
+pub struct Day3;
+
+const INSTR_REG: &str = r"(mul\((\d+),(\d+)\)|do\(\)|don't\(\))";
+
+/// An instruction of the corrupted memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instr {
+    Mul(isize, isize),
+    Do,
+    Dont,
+}
+
+impl Solution for Day3 {
+    type Input = Vec<Instr>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let instr_re = Regex::new(INSTR_REG).unwrap();
+        instr_re
+            .captures_iter(input)
+            .map(|c| {
+                Ok(match &c[0] {
+                    "don't()" => Instr::Dont,
+                    "do()" => Instr::Do,
+                    _ => Instr::Mul(
+                        parse_token(input, c.get(2).unwrap().as_str(), "a number")?,
+                        parse_token(input, c.get(3).unwrap().as_str(), "a number")?,
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, instrs: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for instr in instrs {
+            if let Instr::Mul(op1, op2) = instr {
+                sum += op1 * op2;
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self, instrs: &Self::Input) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        let mut mul_enabled = true;
+        for instr in instrs {
+            match instr {
+                Instr::Dont => mul_enabled = false,
+                Instr::Do => mul_enabled = true,
+                Instr::Mul(op1, op2) if mul_enabled => sum += op1 * op2,
+                Instr::Mul(..) => {}
+            }
+        }
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input1 = indoc! {"
+            xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+        "};
+        let input2 = indoc! {"
+            xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+        "};
+        let solver = Day3 {};
+        assert_eq!(solver.part1(&solver.parse(input1).unwrap()).unwrap(), "161");
+        assert_eq!(solver.part2(&solver.parse(input2).unwrap()).unwrap(), "48");
+    }
+}
@@ -1,4 +1,5 @@
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{grid_size, Answer, Solution, SolveError};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
@@ -65,15 +66,36 @@ impl Direction {
     }
 }
 
+register_day!(2024, 4, "Ceres Search", Day4);
+
 pub struct Day4;
 
-impl DaySolver for Day4 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let lines = input.lines();
-        let height = lines.count();
-        let mut lines = input.lines();
-        let width = lines.next().unwrap().chars().count();
-        let input: Vec<char> = input.replace("\n", "").replace("\r", "").chars().collect();
+pub struct Grid {
+    width: usize,
+    height: usize,
+    chars: Vec<char>,
+}
+
+impl Solution for Day4 {
+    type Input = Grid;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let (width, height) = grid_size(input)?;
+        let chars = input.lines().flat_map(|l| l.chars()).collect();
+        Ok(Grid {
+            width,
+            height,
+            chars,
+        })
+    }
+
+    fn part1(&self, grid: &Self::Input) -> Result<Answer, SolveError> {
+        let Grid {
+            width,
+            height,
+            chars: input,
+        } = grid;
+        let (width, height) = (*width, *height);
 
         let mut sum = 0;
         for y in 0..height {
@@ -90,22 +112,23 @@ impl DaySolver for Day4 {
                             continue;
                         }
                         sum +=
-                            i.test_occurence(&input, width as isize, x as isize, y as isize, false)
+                            i.test_occurence(input, width as isize, x as isize, y as isize, false)
                                 as usize;
                     }
                 }
             }
         }
 
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let lines = input.lines();
-        let height = lines.count();
-        let mut lines = input.lines();
-        let width = lines.next().unwrap().chars().count();
-        let input: Vec<char> = input.replace("\n", "").replace("\r", "").chars().collect();
+    fn part2(&self, grid: &Self::Input) -> Result<Answer, SolveError> {
+        let Grid {
+            width,
+            height,
+            chars: input,
+        } = grid;
+        let (width, height) = (*width, *height);
 
         let mut sum = 0;
         for y in 0..height {
@@ -118,28 +141,28 @@ impl DaySolver for Day4 {
                 }
                 let mut counter = 0;
                 counter += Direction::NW.test_occurence(
-                    &input,
+                    input,
                     width as isize,
                     (x + 1) as isize,
                     (y + 1) as isize,
                     true,
                 ) as usize;
                 counter += Direction::SW.test_occurence(
-                    &input,
+                    input,
                     width as isize,
                     (x + 1) as isize,
                     (y - 1) as isize,
                     true,
                 ) as usize;
                 counter += Direction::NE.test_occurence(
-                    &input,
+                    input,
                     width as isize,
                     (x - 1) as isize,
                     (y + 1) as isize,
                     true,
                 ) as usize;
                 counter += Direction::SE.test_occurence(
-                    &input,
+                    input,
                     width as isize,
                     (x - 1) as isize,
                     (y - 1) as isize,
@@ -152,7 +175,7 @@ impl DaySolver for Day4 {
             }
         }
 
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 }
 
@@ -176,7 +199,7 @@ mod tests {
             MXMXAXMASX
         "};
         let solver = Day4 {};
-        assert_eq!(solver.part1(input).unwrap(), "18");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "18");
 
         let input = indoc! {"
             .M.S......
@@ -190,7 +213,7 @@ mod tests {
             M.M.M.M.M.
             ..........
         "};
-        assert_eq!(solver.part2(input).unwrap(), "9");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "9");
     }
 
     #[test]
@@ -202,7 +225,7 @@ mod tests {
             S
         "};
         let solver = Day4 {};
-        assert_eq!(solver.part1(input).unwrap(), "1");
-        //assert_eq!(solver.part2(input).unwrap(), "TODO");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "1");
+        //assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "TODO");
     }
 }
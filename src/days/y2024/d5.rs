@@ -2,39 +2,61 @@ use std::collections::HashSet;
 
 use petgraph::Graph;
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{parse_token, sections, split_once, Answer, Solution, SolveError};
+
+register_day!(2024, 5, "Print Queue", Day5);
 
 pub struct Day5;
 
-impl DaySolver for Day5 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let input = input.replace("\r", "");
-        let mut input = input.split("\n\n");
+pub struct Manual {
+    /// The page ordering rules as edges
+    graph: Graph<usize, ()>,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Solution for Day5 {
+    type Input = Manual;
 
-        let rules = input.next().unwrap();
-        let updates = input.next().unwrap();
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let sections = sections(input, 2)?;
+        let (rules, updates) = (sections[0], sections[1]);
 
-        let rules: Vec<(u32, u32)> = rules
+        let rules = rules
             .lines()
             .map(|r| {
-                let mut r = r.split("|");
-                let a: u32 = r.next().unwrap().parse().unwrap();
-                let b: u32 = r.next().unwrap().parse().unwrap();
-                (a, b)
+                let (a, b) = split_once(input, r, "|")?;
+                let a: u32 = parse_token(input, a, "a page number")?;
+                let b: u32 = parse_token(input, b, "a page number")?;
+                Ok((a, b))
             })
-            .collect();
+            .collect::<Result<Vec<_>, SolveError>>()?;
+
+        let updates = updates
+            .lines()
+            .map(|u| {
+                u.split(",")
+                    .map(|m| parse_token(input, m, "a page number"))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manual {
+            graph: Graph::<usize, ()>::from_edges(rules.iter()),
+            updates,
+        })
+    }
 
-        let graph = Graph::<usize, ()>::from_edges(rules.iter());
+    fn part1(&self, manual: &Self::Input) -> Result<Answer, SolveError> {
+        let Manual { graph, updates } = manual;
 
         let mut sum = 0;
 
-        updates.lines().for_each(|u| {
-            let vals: Vec<u32> = u.split(",").map(|m| m.parse().unwrap()).collect();
-            let mut u = u.split(",");
-            let mut prev: u32 = u.next().unwrap().parse().unwrap();
+        updates.iter().for_each(|vals| {
+            let mut u = vals.iter();
+            let mut prev = *u.next().unwrap();
             let mut ok = true;
-            u.for_each(|rr| {
-                let rr: u32 = rr.parse().unwrap();
+            u.for_each(|&rr| {
                 let ii = graph.contains_edge(prev.into(), rr.into());
                 if !ii {
                     ok = false;
@@ -48,37 +70,19 @@ impl DaySolver for Day5 {
             sum += vals[vals.len() / 2];
         });
 
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let input = input.replace("\r", "");
-        let mut input = input.split("\n\n");
-
-        let rules = input.next().unwrap();
-        let updates = input.next().unwrap();
-
-        let rules: Vec<(u32, u32)> = rules
-            .lines()
-            .map(|r| {
-                let mut r = r.split("|");
-                let a: u32 = r.next().unwrap().parse().unwrap();
-                let b: u32 = r.next().unwrap().parse().unwrap();
-                (a, b)
-            })
-            .collect();
-
-        let graph = Graph::<usize, ()>::from_edges(rules.iter());
+    fn part2(&self, manual: &Self::Input) -> Result<Answer, SolveError> {
+        let Manual { graph, updates } = manual;
 
         let mut sum = 0;
 
-        updates.lines().for_each(|u| {
-            let vals: Vec<u32> = u.split(",").map(|m| m.parse().unwrap()).collect();
-            let mut u = u.split(",");
-            let mut prev: u32 = u.next().unwrap().parse().unwrap();
+        updates.iter().for_each(|vals| {
+            let mut u = vals.iter();
+            let mut prev = *u.next().unwrap();
             let mut ok = true;
-            u.for_each(|rr| {
-                let rr: u32 = rr.parse().unwrap();
+            u.for_each(|&rr| {
                 let ii = graph.contains_edge(prev.into(), rr.into());
                 if !ii {
                     ok = false;
@@ -118,7 +122,7 @@ impl DaySolver for Day5 {
             sum += correct[vals.len() / 2];
         });
 
-        Some(sum.to_string())
+        Ok(sum.into())
     }
 }
 
@@ -160,7 +164,7 @@ mod tests {
             97,13,75,29,47
         "};
         let solver = Day5 {};
-        assert_eq!(solver.part1(input).unwrap(), "143");
-        assert_eq!(solver.part2(input).unwrap(), "123");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "143");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "123");
     }
 }
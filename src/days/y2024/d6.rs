@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{char_tokens, grid_size, Answer, Solution, SolveError};
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 enum Direction {
@@ -30,7 +31,8 @@ impl Direction {
     }
 }
 
-struct Map {
+#[derive(Clone)]
+pub struct Map {
     obstacles: HashSet<(isize, isize)>,
     width: isize,
     height: isize,
@@ -48,7 +50,8 @@ impl Map {
     }
 }
 
-struct Guard {
+#[derive(Clone)]
+pub struct Guard {
     pos: (isize, isize),
     dir: Direction,
     visited: HashSet<(isize, isize)>,
@@ -133,20 +136,28 @@ impl Guard {
     }
 }
 
+register_day!(2024, 6, "Guard Gallivant", Day6);
+
 pub struct Day6;
 
-impl DaySolver for Day6 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let (mut guard, map) = parse(input);
+impl Solution for Day6 {
+    type Input = (Guard, Map);
 
-        while !guard.has_left_map(&map) {
-            guard.make_move(&map);
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
+
+    fn part1(&self, (guard, map): &Self::Input) -> Result<Answer, SolveError> {
+        let mut guard = guard.clone();
+
+        while !guard.has_left_map(map) {
+            guard.make_move(map);
         }
-        Some(guard.visited.len().to_string())
+        Ok(guard.visited.len().into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let (mut guard, mut map) = parse(input);
+    fn part2(&self, (guard, map): &Self::Input) -> Result<Answer, SolveError> {
+        let (mut guard, mut map) = (guard.clone(), map.clone());
         let mut viable_ob = HashSet::new();
 
         while !guard.has_left_map(&map) {
@@ -156,57 +167,49 @@ impl DaySolver for Day6 {
             guard.make_move(&map);
         }
 
-        Some(viable_ob.len().to_string())
+        Ok(viable_ob.len().into())
     }
 }
 
-fn parse(input: &str) -> (Guard, Map) {
-    let width = input.lines().next().unwrap().chars().count() as isize;
-    let height = input.lines().count() as isize;
+fn parse(input: &str) -> Result<(Guard, Map), SolveError> {
+    let (width, height) = grid_size(input)?;
     let mut obstacles = HashSet::new();
-    let mut guard_pos = (0, 0);
-    let mut guard_dir = Direction::East;
-    input
-        .replace("\r", "")
-        .replace("\n", "")
-        .chars()
-        .enumerate()
-        .for_each(|(i, c)| {
-            let current_pos = (i as isize % width, i as isize / width);
-            match c {
+    let mut guard = None;
+    for (y, l) in input.lines().enumerate() {
+        for (x, (c, token)) in char_tokens(l).enumerate() {
+            let current_pos = (x as isize, y as isize);
+            let dir = match c {
+                '.' => continue,
                 '#' => {
                     obstacles.insert(current_pos);
+                    continue;
                 }
-                '>' => {
-                    guard_pos = current_pos;
-                    guard_dir = Direction::East;
-                }
-                'v' => {
-                    guard_pos = current_pos;
-                    guard_dir = Direction::South;
-                }
-                '<' => {
-                    guard_pos = current_pos;
-                    guard_dir = Direction::West;
-                }
-                '^' => {
-                    guard_pos = current_pos;
-                    guard_dir = Direction::North;
-                }
-                _ => {}
+                '>' => Direction::East,
+                'v' => Direction::South,
+                '<' => Direction::West,
+                '^' => Direction::North,
+                _ => return Err(SolveError::parse(input, token, "one of `.#<>^v`")),
+            };
+            if guard.is_some() {
+                return Err(SolveError::parse(input, token, "a single guard"));
             }
-        });
+            guard = Some((current_pos, dir));
+        }
+    }
+    let Some((guard_pos, guard_dir)) = guard else {
+        return Err(SolveError::parse(input, &input[input.len()..], "a guard"));
+    };
     let map = Map {
         obstacles,
-        width,
-        height,
+        width: width as isize,
+        height: height as isize,
     };
     let guard = Guard {
         pos: guard_pos,
         dir: guard_dir,
         visited: HashSet::new(),
     };
-    (guard, map)
+    Ok((guard, map))
 }
 
 #[cfg(test)]
@@ -229,7 +232,7 @@ mod tests {
             ......#...
         "};
         let solver = Day6 {};
-        assert_eq!(solver.part1(input).unwrap(), "41");
-        assert_eq!(solver.part2(input).unwrap(), "6");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "41");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "6");
     }
 }
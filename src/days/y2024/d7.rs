@@ -0,0 +1,112 @@
+use crate::register_day;
+use crate::utils::{parse_token, split_once, Answer, Solution, SolveError};
+
+register_day!(2024, 7, "Bridge Repair", Day7);
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(usize, Vec<usize>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .map(|l| {
+                let (res, operands) = split_once(input, l, ": ")?;
+                let res = parse_token(input, res, "a number")?;
+                let operands = operands
+                    .split(" ")
+                    .map(|o| parse_token(input, o, "a number"))
+                    .collect::<Result<_, _>>()?;
+                Ok((res, operands))
+            })
+            .collect()
+    }
+
+    fn part1(&self, equations: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(equations
+            .iter()
+            .fold(0, |mut sum, (res, operands)| {
+                if check(*res, operands[0], &operands[1..], false) {
+                    sum += *res;
+                }
+                sum
+            })
+            .into())
+    }
+
+    fn part2(&self, equations: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(equations
+            .iter()
+            .fold(0, |mut sum, (res, operands)| {
+                if check(*res, operands[0], &operands[1..], true) {
+                    sum += *res;
+                }
+                sum
+            })
+            .into())
+    }
+}
+
+fn get_multiplicator(val: usize) -> usize {
+    let mut cur = 10;
+    while val > cur {
+        cur *= 10;
+    }
+    cur
+}
+
+fn check(res: usize, cur: usize, operands: &[usize], part2: bool) -> bool {
+    if operands.is_empty() {
+        return res == cur;
+    }
+
+    if cur > res {
+        return false;
+    }
+
+    let next = *operands.first().unwrap();
+    let new_operands = &operands[1..];
+
+    let add = check(res, cur + next, new_operands, part2);
+    let mul = check(res, cur * next, new_operands, part2);
+    let concat = if part2 {
+        check(
+            res,
+            cur * get_multiplicator(next) + next,
+            new_operands,
+            part2,
+        )
+    } else {
+        false
+    };
+
+    add || mul || concat
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            190: 10 19
+            3267: 81 40 27
+            83: 17 5
+            156: 15 6
+            7290: 6 8 6 15
+            161011: 16 10 13
+            192: 17 8 14
+            21037: 9 7 18 13
+            292: 11 6 16 20
+        "};
+        let solver = Day7 {};
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "3749");
+        assert_eq!(
+            solver.part2(&solver.parse(input).unwrap()).unwrap(),
+            "11387"
+        );
+    }
+}
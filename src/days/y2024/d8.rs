@@ -1,18 +1,27 @@
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-use crate::utils::DaySolver;
+use crate::register_day;
+use crate::utils::{char_tokens, grid_size, Answer, Solution, SolveError};
+
+register_day!(2024, 8, "Resonant Collinearity", Day8);
 
 pub struct Day8;
 
-type Antennas = HashMap<char, Vec<(isize, isize)>>;
+pub type Antennas = HashMap<char, Vec<(isize, isize)>>;
+
+impl Solution for Day8 {
+    type Input = (isize, isize, Antennas);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
 
-impl DaySolver for Day8 {
-    fn part1(&self, input: &str) -> Option<String> {
-        let (width, height, antennas) = parse(input);
+    fn part1(&self, (width, height, antennas): &Self::Input) -> Result<Answer, SolveError> {
+        let (width, height) = (*width, *height);
         let mut antinodes = HashSet::new();
 
-        for (_, positions) in antennas {
+        for positions in antennas.values() {
             for p in positions.iter().permutations(2) {
                 let a = p[0];
                 let b = p[1];
@@ -21,14 +30,14 @@ impl DaySolver for Day8 {
             }
         }
 
-        Some(antinodes.len().to_string())
+        Ok(antinodes.len().into())
     }
 
-    fn part2(&self, input: &str) -> Option<String> {
-        let (width, height, antennas) = parse(input);
+    fn part2(&self, (width, height, antennas): &Self::Input) -> Result<Answer, SolveError> {
+        let (width, height) = (*width, *height);
         let mut antinodes = HashSet::new();
 
-        for (_, positions) in antennas {
+        for positions in antennas.values() {
             for p in positions.iter().permutations(2) {
                 let a = p[0];
                 let b = p[1];
@@ -37,7 +46,7 @@ impl DaySolver for Day8 {
             }
         }
 
-        Some(antinodes.len().to_string())
+        Ok(antinodes.len().into())
     }
 }
 
@@ -72,28 +81,25 @@ fn get_antinodes(
     antinodes
 }
 
-fn parse(input: &str) -> (isize, isize, Antennas) {
-    let width = input.lines().next().unwrap().chars().count() as isize;
-    let height = input.lines().count() as isize;
+fn parse(input: &str) -> Result<(isize, isize, Antennas), SolveError> {
+    let (width, height) = grid_size(input)?;
     let mut antennas = HashMap::new();
-    input
-        .replace("\r", "")
-        .replace("\n", "")
-        .chars()
-        .enumerate()
-        .for_each(|(i, c)| {
-            let current_pos = (i as isize % width, i as isize / width);
+    for (y, l) in input.lines().enumerate() {
+        for (x, (c, token)) in char_tokens(l).enumerate() {
+            let current_pos = (x as isize, y as isize);
             match c {
                 '.' => {}
-                c => {
+                c if c.is_ascii_alphanumeric() => {
                     antennas
                         .entry(c)
                         .and_modify(|e: &mut Vec<(isize, isize)>| e.push(current_pos))
                         .or_insert(vec![current_pos]);
                 }
+                _ => return Err(SolveError::parse(input, token, "`.` or an antenna")),
             }
-        });
-    (width, height, antennas)
+        }
+    }
+    Ok((width as isize, height as isize, antennas))
 }
 
 #[cfg(test)]
@@ -118,7 +124,7 @@ mod tests {
             ............
         "};
         let solver = Day8 {};
-        assert_eq!(solver.part1(input).unwrap(), "14");
-        assert_eq!(solver.part2(input).unwrap(), "34");
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "14");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "34");
     }
 }
@@ -0,0 +1,274 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::register_day;
+use crate::utils::{char_tokens, parse_token, trace, Answer, Solution, SolveError};
+
+pub type FileSystem = Vec<FSType>;
+
+register_day!(2024, 9, "Disk Fragmenter", Day9);
+register_day!(2024, 9, "Disk Fragmenter", Day9Spans, variant = "spans");
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = FileSystem;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
+
+    fn part1(&self, filesystem: &Self::Input) -> Result<Answer, SolveError> {
+        let mut filesystem = filesystem.clone();
+
+        let mut empty_block_idx = 1;
+        'outer: loop {
+            let (id, mut length) = loop {
+                match filesystem.pop().unwrap() {
+                    FSType::Free(_) => {}
+                    FSType::Block(id, length) => break (id, length),
+                }
+            };
+
+            while length > 0 {
+                let Some(file) = filesystem.get_mut(empty_block_idx) else {
+                    filesystem.push(FSType::Block(id, length));
+                    break 'outer;
+                };
+                let FSType::Free(empty_size) = file else {
+                    empty_block_idx += 1;
+                    continue;
+                };
+                let to_insert = if length < *empty_size {
+                    *empty_size -= length;
+                    let file = FSType::Block(id, length);
+                    length = 0;
+                    file
+                } else {
+                    length -= *empty_size;
+                    let file = FSType::Block(id, *empty_size);
+                    *empty_size = 0;
+                    file
+                };
+                if *empty_size == 0 {
+                    filesystem.remove(empty_block_idx);
+                }
+                filesystem.insert(empty_block_idx, to_insert);
+                empty_block_idx += 1;
+            }
+        }
+
+        trace::grid("compacted disk", || render(&filesystem));
+        let checksum = get_checksum(&filesystem);
+        Ok(checksum.into())
+    }
+
+    fn part2(&self, filesystem: &Self::Input) -> Result<Answer, SolveError> {
+        let mut filesystem = filesystem.clone();
+
+        let mut block_idx = filesystem.len() - 1;
+        let mut min_block = None;
+        while block_idx > 0 {
+            let (removed_block, id, length) = loop {
+                let removed_block = filesystem[block_idx];
+                match removed_block {
+                    FSType::Free(_) => {
+                        block_idx -= 1;
+                    }
+                    FSType::Block(id, length) => break (removed_block, id, length),
+                }
+            };
+            if min_block.is_some_and(|m| id >= m) {
+                block_idx -= 1;
+                continue;
+            }
+            min_block = Some(id);
+
+            let mut empty_block_idx = 0;
+            let mut ok = false;
+            while empty_block_idx < block_idx {
+                let FSType::Free(s) = &mut filesystem[empty_block_idx] else {
+                    empty_block_idx += 1;
+                    continue;
+                };
+                if length > *s {
+                    empty_block_idx += 1;
+                    continue;
+                }
+                *s -= length;
+                if *s == 0 {
+                    filesystem.remove(empty_block_idx);
+                } else {
+                    block_idx += 1;
+                }
+                filesystem.insert(empty_block_idx, removed_block);
+                ok = true;
+                break;
+            }
+            if ok {
+                filesystem[block_idx] = FSType::Free(length);
+            }
+            block_idx -= 1;
+        }
+
+        trace::grid("compacted disk", || render(&filesystem));
+        let checksum = get_checksum(&filesystem);
+        Ok(checksum.into())
+    }
+}
+
+/// Compacts by positions instead of moving entries of the filesystem around
+pub struct Day9Spans;
+
+impl Solution for Day9Spans {
+    type Input = FileSystem;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse(input)
+    }
+
+    fn part1(&self, filesystem: &Self::Input) -> Result<Answer, SolveError> {
+        let mut blocks: Vec<Option<usize>> = vec![];
+        for entry in filesystem {
+            match *entry {
+                FSType::Block(id, length) => blocks.extend(std::iter::repeat_n(Some(id), length)),
+                FSType::Free(length) => blocks.extend(std::iter::repeat_n(None, length)),
+            }
+        }
+
+        let (mut free, mut last) = (0, blocks.len() - 1);
+        loop {
+            while free < last && blocks[free].is_some() {
+                free += 1;
+            }
+            while free < last && blocks[last].is_none() {
+                last -= 1;
+            }
+            if free >= last {
+                break;
+            }
+            blocks.swap(free, last);
+        }
+
+        let checksum: usize = blocks
+            .iter()
+            .enumerate()
+            .map(|(idx, id)| idx * id.unwrap_or(0))
+            .sum();
+        Ok(checksum.into())
+    }
+
+    fn part2(&self, filesystem: &Self::Input) -> Result<Answer, SolveError> {
+        // The start positions of the free spans, one min-heap per span length
+        let mut free: Vec<BinaryHeap<Reverse<usize>>> = vec![BinaryHeap::new(); 10];
+        let mut files = vec![];
+        let mut pos = 0;
+        for entry in filesystem {
+            match *entry {
+                FSType::Block(id, length) => files.push((id, pos, length)),
+                FSType::Free(length) => free[length].push(Reverse(pos)),
+            }
+            pos += match *entry {
+                FSType::Block(_, length) | FSType::Free(length) => length,
+            };
+        }
+
+        let mut checksum = 0;
+        for &(id, start, length) in files.iter().rev() {
+            let span = (length..free.len())
+                .filter_map(|size| Some((free[size].peek()?.0, size)))
+                .filter(|(span_start, _)| *span_start < start)
+                .min();
+            let start = match span {
+                Some((span_start, size)) => {
+                    free[size].pop();
+                    if size > length {
+                        free[size - length].push(Reverse(span_start + length));
+                    }
+                    span_start
+                }
+                None => start,
+            };
+            checksum += (start..start + length).map(|idx| idx * id).sum::<usize>();
+        }
+        Ok(checksum.into())
+    }
+}
+
+fn get_checksum(filesystem: &FileSystem) -> usize {
+    let mut checksum = 0;
+    let mut idx = 0;
+    for i in filesystem {
+        match i {
+            FSType::Free(length) => idx += length,
+            FSType::Block(id, length) => {
+                let file: usize = (idx..idx + length).map(|idx| id * idx).sum();
+                trace::contribution(|| (format!("file {id} at {idx}"), file));
+                checksum += file;
+                idx += length;
+            }
+        }
+    }
+    checksum
+}
+
+fn parse(input: &str) -> Result<FileSystem, SolveError> {
+    let mut is_file = true;
+    let mut id = 0;
+    let mut filesystem = vec![];
+    for (_, token) in input.lines().flat_map(char_tokens) {
+        is_file = !is_file;
+        let length: usize = parse_token(input, token, "a digit")?;
+        if length == 0 {
+            continue;
+        }
+        let entry = match !is_file {
+            true => {
+                id += 1;
+                FSType::Block(id - 1, length)
+            }
+            false => FSType::Free(length),
+        };
+        filesystem.push(entry);
+    }
+    if id == 0 {
+        return Err(SolveError::parse(input, input.trim(), "a disk map"));
+    }
+    Ok(filesystem)
+}
+
+#[derive(Clone, Copy)]
+pub enum FSType {
+    Block(usize, usize),
+    Free(usize),
+}
+
+/// Renders the blocks of the filesystem like the puzzle does, e.g. `00992111777.44.333....5555.6666.....8888..`
+fn render(filesystem: &FileSystem) -> String {
+    filesystem
+        .iter()
+        .map(|entry| match *entry {
+            FSType::Block(id, length) => id.to_string().repeat(length),
+            FSType::Free(length) => ".".repeat(length),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn sample() {
+        let input = indoc! {"
+            2333133121414131402
+        "};
+        let solver = Day9 {};
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "1928");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "2858");
+
+        let solver = Day9Spans {};
+        assert_eq!(solver.part1(&solver.parse(input).unwrap()).unwrap(), "1928");
+        assert_eq!(solver.part2(&solver.parse(input).unwrap()).unwrap(), "2858");
+    }
+}
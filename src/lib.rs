@@ -0,0 +1,14 @@
+//! Advent of Code solutions and the tooling around them
+//!
+//! Solvers register themselves with [`register_day!`] and are looked up through the registry of
+//! [`days`]. The [`utils`] hold the solver traits, the input cache and the shared parsing helpers.
+//! Fetching from the AoC website requires the `fetch` feature, without it only cached files are
+//! used. The library never prints: the command line interface lives in the binary, behind the
+//! `cli` feature.
+//!
+
+pub mod days;
+pub mod utils;
+
+pub use days::{get_solver, registry, variants, years};
+pub use utils::{Answer, DaySolver, Params, Registration, Solution, SolveError};
@@ -1,38 +1,234 @@
-mod days;
-mod utils;
+mod cli;
 
-use std::error::Error;
+use std::{collections::BTreeMap, error::Error, path::PathBuf};
 
-use utils::*;
+use aoc_2024::utils::{
+    check_param_overrides, event, init_profile, parse_param, select_year, set_no_cache,
+    set_offline, set_param_overrides, set_project_dir, Layout,
+};
+use clap::{
+    builder::{PossibleValuesParser, TypedValueParser},
+    Parser, Subcommand,
+};
+use cli::*;
+use strum::VariantNames;
 
-const AOC_YEAR: u16 = 2024;
+const AOC_FIRST_YEAR: u16 = 2015;
+/// The days any event can have, the calendar of the selected event is checked at runtime
 const AOC_FIRST_DAY: u8 = 1;
 const AOC_LAST_DAY: u8 = 25;
 const X_PADDING: usize = 1;
 
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// The event to work on (defaults to the AOC_YEAR env variable, then the latest year with solvers)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(AOC_FIRST_YEAR as i64..))]
+    year: Option<u16>,
+    /// The session profile to use (defaults to the AOC_PROFILE env variable)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    /// Where tokens are looked up and inputs are cached (defaults to the AOC_LAYOUT env variable)
+    #[arg(long, global = true, value_parser = PossibleValuesParser::new(Layout::VARIANTS).try_map(|l| l.parse::<Layout>()))]
+    layout: Option<Layout>,
+    /// The root directory of the input cache (defaults to the AOC_CACHE_DIR env variable, then `inputs/` of this crate)
+    #[arg(long, global = true)]
+    cache_dir: Option<PathBuf>,
+    /// Never touch the network, only use cached files (also enabled by the AOC_OFFLINE env variable)
+    #[arg(long, global = true)]
+    offline: bool,
+    /// Overrides a named parameter of the solver, e.g. `--param width=11` (repeatable)
+    #[arg(long = "param", global = true, value_name = "NAME=VALUE", value_parser = parse_param)]
+    params: Vec<(String, i64)>,
+    /// Show the events traced by the solvers below their results, or write them to FILE as JSON lines with `--explain=FILE`
+    #[arg(long, global = true, value_name = "FILE", num_args = 0..=1, require_equals = true, default_missing_value = "-")]
+    explain: Option<PathBuf>,
+    /// Recompute every answer instead of using the answer cache enabled by AOC_ANSWER_CACHE
+    #[arg(long, global = true)]
+    no_cache: bool,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Render the puzzle description of the given day
+    Show {
+        #[arg(value_parser = clap::value_parser!(u8).range(AOC_FIRST_DAY as i64..=AOC_LAST_DAY as i64))]
+        day: u8,
+    },
+    /// Run the solver of the given day on every input in its input directory (e.g. one per team member)
+    Batch {
+        #[arg(value_parser = clap::value_parser!(u8).range(AOC_FIRST_DAY as i64..=AOC_LAST_DAY as i64))]
+        day: u8,
+        /// The input directory (defaults to `day{NN}/` in the cache directory)
+        #[arg(long)]
+        dir: Option<PathBuf>,
+        /// Write the answers to `{name}.answers` for inputs without recorded answers
+        #[arg(long)]
+        record: bool,
+    },
+    /// Run every variant of the given day on its input and compare their answers and timings
+    Compare {
+        #[arg(value_parser = clap::value_parser!(u8).range(AOC_FIRST_DAY as i64..=AOC_LAST_DAY as i64))]
+        day: u8,
+    },
+    /// Extract the example inputs and answers of the given day into test fixtures
+    Extract {
+        #[arg(value_parser = clap::value_parser!(u8).range(AOC_FIRST_DAY as i64..=AOC_LAST_DAY as i64))]
+        day: u8,
+    },
+    /// Show a private leaderboard (defaults to the AOC_LEADERBOARD_ID env variable)
+    Leaderboard {
+        id: Option<String>,
+        /// Show the completion times of the given day instead of the overall ranking
+        #[arg(long, value_parser = clap::value_parser!(u8).range(AOC_FIRST_DAY as i64..=AOC_LAST_DAY as i64))]
+        day: Option<u8>,
+    },
+    /// List the solved days with their titles and parameters
+    List,
+    /// Compare the stars collected on the AoC website with the local solvers
+    Progress,
+    /// Check the session token of the active profile
+    Whoami,
+    /// Store the session token of the active profile in the token files of aocd (with `--layout aocd`)
+    Login { token: String },
+    /// Download the input of the given day, or all unlocked inputs at once
+    Fetch {
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(AOC_FIRST_DAY as i64..=AOC_LAST_DAY as i64))]
+        day: Option<u8>,
+        /// Download every unlocked input which is not cached yet
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+    },
+    /// Move the cached files of the former flat `inputs/day{N}.txt` layout into the cache directory
+    Migrate {
+        /// The directory holding the flat layout
+        #[arg(long, default_value = "inputs")]
+        from: PathBuf,
+    },
+    /// Encrypt or decrypt every cached input with the key from AOC_CACHE_KEY or AOC_CACHE_KEY_FILE
+    Crypt {
+        /// Store the inputs encrypted, so they can be committed
+        #[arg(long, required_unless_present = "decrypt", conflicts_with = "decrypt")]
+        encrypt: bool,
+        /// Store the inputs as plain text again
+        #[arg(long)]
+        decrypt: bool,
+    },
+}
+
+impl Command {
+    /// The day the command is about, if any
+    fn day(&self) -> Option<u8> {
+        match self {
+            Self::Show { day }
+            | Self::Batch { day, .. }
+            | Self::Compare { day }
+            | Self::Extract { day } => Some(*day),
+            Self::Leaderboard { day, .. } | Self::Fetch { day, .. } => *day,
+            _ => None,
+        }
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    display_banner("Advent of Code 2024", X_PADDING, true);
-    if dotenvy::dotenv().is_err() {
+    let cli = Cli::parse();
+    set_project_dir(PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+
+    let env_loaded = dotenvy::dotenv().is_ok();
+    let year = select_year(cli.year);
+    let title = format!("Advent of Code {year}");
+
+    display_banner(&title, X_PADDING, true);
+    if !env_loaded {
         println!("No .env file detected. AOC_SESSION variable may not be set and auto-fetching puzzle inputs is not possible!")
     }
+    init_profile(cli.profile, cli.layout, cli.cache_dir);
+    set_offline(cli.offline);
+    set_param_overrides(cli.params);
+    set_no_cache(cli.no_cache);
+    set_explain(cli.explain.map(|path| match path.as_os_str() == "-" {
+        true => Explain::Render,
+        false => Explain::File(path),
+    }))?;
 
-    if let Ok(day) = get_day() {
-        display_banner(
-            &format!("Advent of Code 2024 - Day {}", day),
-            X_PADDING,
-            true,
-        );
-        return solve_day(day).await;
+    if let Some(day) = cli.command.as_ref().and_then(Command::day) {
+        check_day(year, day)?;
+        check_param_overrides(year, Some(day))?;
+    }
+
+    match cli.command {
+        Some(Command::Show { day }) => {
+            display_banner(&format!("{title} - Day {day}"), X_PADDING, true);
+            return show_day(year, day).await;
+        }
+        Some(Command::Batch { day, dir, record }) => {
+            display_banner(&format!("{title} - Day {day} Batch"), X_PADDING, true);
+            return batch_day(year, day, dir, record);
+        }
+        Some(Command::Compare { day }) => {
+            display_banner(&format!("{title} - Day {day} Compare"), X_PADDING, true);
+            return compare_day(year, day).await;
+        }
+        Some(Command::Extract { day }) => {
+            display_banner(&format!("{title} - Day {day}"), X_PADDING, true);
+            return extract_day(year, day).await;
+        }
+        Some(Command::Leaderboard { id, day }) => {
+            display_banner(&format!("{title} - Leaderboard"), X_PADDING, true);
+            return show_leaderboard(year, id, day).await;
+        }
+        Some(Command::List) => {
+            display_banner(&format!("{title} - Days"), X_PADDING, true);
+            return list_days(year);
+        }
+        Some(Command::Progress) => {
+            display_banner(&format!("{title} - Progress"), X_PADDING, true);
+            return show_progress(year).await;
+        }
+        Some(Command::Whoami) => {
+            display_banner(&format!("{title} - Whoami"), X_PADDING, true);
+            return show_whoami(year).await;
+        }
+        Some(Command::Login { token }) => {
+            display_banner(&format!("{title} - Login"), X_PADDING, true);
+            return login(&token);
+        }
+        Some(Command::Fetch { day, .. }) => {
+            display_banner(&format!("{title} - Fetch"), X_PADDING, true);
+            return fetch_inputs(year, day).await;
+        }
+        Some(Command::Migrate { from }) => {
+            display_banner(&format!("{title} - Migrate"), X_PADDING, true);
+            return migrate_cache(year, &from);
+        }
+        Some(Command::Crypt { encrypt, .. }) => {
+            display_banner(&format!("{title} - Crypt"), X_PADDING, true);
+            return crypt_cache(encrypt);
+        }
+        None => {}
+    }
+
+    if let Ok(day) = get_day(year) {
+        check_param_overrides(year, Some(day))?;
+        display_banner(&format!("{title} - Day {day}"), X_PADDING, true);
+        solve_day(year, day).await?;
+        return Ok(());
     }
 
-    for day in AOC_FIRST_DAY..=AOC_LAST_DAY {
+    check_param_overrides(year, None)?;
+    let event = event(year);
+    let mut solved = BTreeMap::new();
+    for day in event.days() {
         display_banner(
-            &format!("Advent of Code 2024 - Day {}", day),
+            &format!("{title} - Day {day}"),
             X_PADDING,
-            day == AOC_FIRST_DAY,
+            day == event.first_day,
         );
-        solve_day(day).await?
+        solved.insert(day, solve_day(year, day).await?);
     }
+    display_stars(year, &solved);
     Ok(())
 }
@@ -0,0 +1,267 @@
+//! answer.rs
+//!
+//! Typed answers of the solvers
+//!
+//! Answers are normalized on construction: non-negative integers are always `Unsigned` and text
+//! holding a canonical integer becomes a number. So answers compare equal regardless of the type a
+//! solver used, and answers stored as text (e.g. in `.answers` files) are read back the same way.
+//! Comparing and hashing goes through the same normalization, so it also holds for answers built
+//! from the variants directly.
+//! In JSON, integers are numbers (strings beyond 64 bit), text is a string and an unsolved part is
+//! `null`.
+//!
+
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+    mem,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Debug, Clone, Default)]
+pub enum Answer {
+    /// A negative integer
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+    #[default]
+    Unsolved,
+}
+
+impl Answer {
+    /// Reads an answer from its textual form, the inverse of its `Display` implementation
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if s.is_empty() {
+            return Self::Unsolved;
+        }
+        match s.parse::<i128>() {
+            // Only canonical integers, so text like `007` or `+1` stays text
+            Ok(n) if n.to_string() == s => Self::from(n),
+            _ => match s.parse::<u128>() {
+                Ok(n) if n.to_string() == s => Self::Unsigned(n),
+                _ => Self::Text(s.to_string()),
+            },
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        *self != Self::Unsolved
+    }
+
+    /// The normalized form of the answer, as built by its `From` implementations
+    fn normalized(&self) -> Cow<'_, Self> {
+        match self {
+            Self::Signed(n) if *n >= 0 => Cow::Owned(Self::Unsigned(*n as u128)),
+            Self::Text(s) => match Self::parse(s) {
+                Self::Text(parsed) if parsed == *s => Cow::Borrowed(self),
+                parsed => Cow::Owned(parsed),
+            },
+            _ => Cow::Borrowed(self),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (&*self.normalized(), &*other.normalized()) {
+            (Self::Signed(a), Self::Signed(b)) => a == b,
+            (Self::Unsigned(a), Self::Unsigned(b)) => a == b,
+            (Self::Text(a), Self::Text(b)) => a == b,
+            (Self::Unsolved, Self::Unsolved) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalized();
+        mem::discriminant(&*normalized).hash(state);
+        match &*normalized {
+            Self::Signed(n) => n.hash(state),
+            Self::Unsigned(n) => n.hash(state),
+            Self::Text(s) => s.hash(state),
+            Self::Unsolved => {}
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Self::Unsigned(n as u128)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                match n < 0 {
+                    true => Self::Signed(n as i128),
+                    false => Self::Unsigned(n as u128),
+                }
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::parse(s)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        *self == Self::parse(other)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Signed(n) => write!(f, "{n}"),
+            Self::Unsigned(n) => write!(f, "{n}"),
+            Self::Text(s) => write!(f, "{s}"),
+            Self::Unsolved => Ok(()),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            // JSON parsers commonly read integers beyond 64 bit as floats, so those are strings
+            Self::Signed(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.serialize_str(&n.to_string()),
+            },
+            Self::Unsigned(n) => match u64::try_from(*n) {
+                Ok(n) => serializer.serialize_u64(n),
+                Err(_) => serializer.serialize_str(&n.to_string()),
+            },
+            Self::Text(s) => serializer.serialize_str(s),
+            Self::Unsolved => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl de::Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an integer, a string or null")
+            }
+
+            fn visit_i64<E>(self, n: i64) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_u64<E>(self, n: u64) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_i128<E>(self, n: i128) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_u128<E>(self, n: u128) -> Result<Answer, E> {
+                Ok(n.into())
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Answer, E> {
+                Ok(s.into())
+            }
+
+            fn visit_unit<E>(self) -> Result<Answer, E> {
+                Ok(Answer::Unsolved)
+            }
+
+            fn visit_none<E>(self) -> Result<Answer, E> {
+                Ok(Answer::Unsolved)
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn normalized_comparison() {
+        assert_eq!(Answer::from(11_isize), Answer::from(11_u8));
+        assert_eq!(Answer::from(-3_i64), Answer::Signed(-3));
+        assert_eq!(
+            Answer::from(u128::MAX),
+            "340282366920938463463374607431768211455"
+        );
+        assert_eq!(Answer::from(-7_i32), "-7");
+        assert_eq!(Answer::from("LGYHTR"), "LGYHTR");
+        assert_eq!(Answer::parse("007"), Answer::Text("007".to_string()));
+        assert_eq!(Answer::parse(" "), Answer::Unsolved);
+        assert!(matches!(Answer::from("42"), Answer::Unsigned(42)));
+        assert!(matches!(Answer::from("abc"), Answer::Text(_)));
+    }
+
+    #[test]
+    fn variants_compare_normalized() {
+        assert_eq!(Answer::Signed(5), Answer::Unsigned(5));
+        assert_eq!(Answer::Text("42".to_string()), Answer::Unsigned(42));
+        assert_eq!(Answer::Text("-7".to_string()), Answer::Signed(-7));
+        assert_eq!(Answer::Text(String::new()), Answer::Unsolved);
+        assert_ne!(Answer::Text("007".to_string()), Answer::Unsigned(7));
+        assert_ne!(Answer::Signed(-5), Answer::Unsigned(5));
+
+        let answers: HashSet<Answer> = [
+            Answer::Signed(5),
+            Answer::Unsigned(5),
+            Answer::Text("5".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(answers.len(), 1);
+    }
+
+    #[test]
+    fn json() {
+        let answers = vec![
+            Answer::from(u128::MAX),
+            Answer::from(-1_isize),
+            Answer::from("6,0"),
+            Answer::Unsolved,
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r#"["340282366920938463463374607431768211455",-1,"6,0",null]"#
+        );
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+}
@@ -0,0 +1,206 @@
+//! answer_cache.rs
+//!
+//! Opt-in cache of the answers of the solvers, enabled by the `AOC_ANSWER_CACHE` env variable
+//!
+//! The answers of a year are stored in `answers.json` of its cache directory, one entry per day and
+//! part. An entry is only used while the SHA-256 hash of the input and the fingerprint of the solver
+//! still match. The fingerprint is made of the version declared by the solver (see
+//! [`Solution::VERSION`](super::Solution::VERSION)), or the hash of the running build otherwise,
+//! and the values of its parameters. `--no-cache` recomputes every answer and stores the fresh ones.
+//! Like inputs, the answers are encrypted with the cache key.
+//!
+
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fs,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    active_profile,
+    cache::{read_private, sha256_hex, write_private},
+    env_enabled, Answer, DaySolver, Params,
+};
+
+const ANSWER_CACHE_VAR: &str = "AOC_ANSWER_CACHE";
+pub const ANSWERS_FILE: &str = "answers.json";
+
+static NO_CACHE: AtomicBool = AtomicBool::new(false);
+static BUILD_HASH: OnceLock<String> = OnceLock::new();
+
+/// What a cached answer was computed from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerKey {
+    /// The SHA-256 hash of the input
+    pub input: String,
+    /// The version or build hash of the solver together with its parameter values
+    pub solver: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedAnswer {
+    #[serde(flatten)]
+    key: AnswerKey,
+    answer: Answer,
+}
+
+/// The cached answers of a year by `day{NN}.part{N}`
+type Answers = BTreeMap<String, CachedAnswer>;
+
+/// Forces the recomputation of every answer for the rest of the run
+pub fn set_no_cache(no_cache: bool) {
+    NO_CACHE.store(no_cache, Ordering::Relaxed);
+}
+
+/// The key of the answers of the solver on the input, `None` unless the `AOC_ANSWER_CACHE` env
+/// variable enables the cache
+pub fn answer_key(solver: &dyn DaySolver, params: &Params, input: &str) -> Option<AnswerKey> {
+    env_enabled(ANSWER_CACHE_VAR).then(|| AnswerKey::new(solver, params, input))
+}
+
+/// The hash of the running executable, so answers are recomputed after every change of the code
+fn build_hash() -> &'static str {
+    BUILD_HASH.get_or_init(|| {
+        let build = env::current_exe().and_then(fs::read).unwrap_or_default();
+        sha256_hex(&build)[..16].to_string()
+    })
+}
+
+impl AnswerKey {
+    pub fn new(solver: &dyn DaySolver, params: &Params, input: &str) -> Self {
+        let version = match solver.version() {
+            Some(version) => format!("v{version}"),
+            None => format!("build {}", build_hash()),
+        };
+        let params: Vec<String> = solver
+            .params()
+            .iter()
+            .map(|param| format!("{}={}", param.name, params.value(param)))
+            .collect();
+        Self {
+            input: sha256_hex(input.as_bytes()),
+            solver: [version]
+                .into_iter()
+                .chain(params)
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+fn entry(day: u8, part: u8) -> String {
+    format!("day{day:02}.part{part}")
+}
+
+fn read_answers(path: &Path) -> Result<Answers, Box<dyn Error>> {
+    match read_private(path)? {
+        Some(json) => Ok(serde_json::from_str(&json)
+            .map_err(|e| format!("Invalid answer cache `{}`: {e}", path.display()))?),
+        None => Ok(Answers::default()),
+    }
+}
+
+fn cached_in(answers: &Answers, day: u8, part: u8, key: &AnswerKey) -> Option<Answer> {
+    answers
+        .get(&entry(day, part))
+        .filter(|cached| cached.key == *key)
+        .map(|cached| cached.answer.clone())
+}
+
+/// The cached answers of the given parts, `None` unless every part is cached for the key
+pub fn cached_answers(
+    year: u16,
+    day: u8,
+    parts: u8,
+    key: &AnswerKey,
+) -> Result<Option<Vec<Answer>>, Box<dyn Error>> {
+    if NO_CACHE.load(Ordering::Relaxed) {
+        return Ok(None);
+    }
+    let answers = read_answers(&active_profile().cache_dir(year).join(ANSWERS_FILE))?;
+    Ok((1..=parts)
+        .map(|part| cached_in(&answers, day, part, key))
+        .collect())
+}
+
+/// Stores the solved answers of a day, replacing the former answers of their parts
+pub fn store_answers(
+    year: u16,
+    day: u8,
+    key: &AnswerKey,
+    solved: &[(u8, Answer)],
+) -> Result<(), Box<dyn Error>> {
+    if solved.is_empty() {
+        return Ok(());
+    }
+    let path = active_profile().cache_dir(year).join(ANSWERS_FILE);
+    let mut answers = read_answers(&path)?;
+    for (part, answer) in solved {
+        answers.insert(
+            entry(day, *part),
+            CachedAnswer {
+                key: key.clone(),
+                answer: answer.clone(),
+            },
+        );
+    }
+    write_private(&path, &serde_json::to_string_pretty(&answers)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{Param, Solution, SolveError};
+
+    struct Grid;
+
+    impl Solution for Grid {
+        type Input = ();
+        const PARAMS: &'static [Param] = &[Param {
+            name: "width",
+            default: 101,
+            sample: 11,
+            min: 1,
+            max: 1000,
+        }];
+        const VERSION: Option<u32> = Some(2);
+
+        fn parse(&self, _input: &str) -> Result<(), SolveError> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &()) -> Result<Answer, SolveError> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    #[test]
+    fn answer_keys() {
+        let key = AnswerKey::new(&Grid, &Params::default(), "abc");
+        assert_eq!(key.solver, "v2 width=101");
+        assert_eq!(key.input, sha256_hex(b"abc"));
+        assert_ne!(key, AnswerKey::new(&Grid, &Params::sample(), "abc"));
+
+        let mut answers = Answers::default();
+        answers.insert(
+            entry(14, 1),
+            CachedAnswer {
+                key: key.clone(),
+                answer: Answer::from(12_u32),
+            },
+        );
+        assert_eq!(cached_in(&answers, 14, 1, &key), Some(Answer::from(12_u32)));
+        assert_eq!(cached_in(&answers, 14, 2, &key), None);
+        let changed = AnswerKey::new(&Grid, &Params::default(), "abd");
+        assert_eq!(cached_in(&answers, 14, 1, &changed), None);
+    }
+}
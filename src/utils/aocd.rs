@@ -0,0 +1,152 @@
+//! aocd.rs
+//!
+//! Interoperability with the token and cache layout of `aocd` (advent-of-code-data)
+//!
+//! * `{config}/token` - The token of the default account
+//! * `{config}/tokens.json` - The tokens of named accounts, `{"alt": "<token>"}`
+//! * `{config}/token2id.json` - Maps a token to the user id naming its cache directory. Inputs of
+//!   tokens without user id are cached locally, so tokens never end up in paths.
+//! * `{data}/{user id}/{year}_{day:02}_input.txt` - The cached inputs
+//!
+//! Tokens are read from the token files, and stored into them by the `login` command, readable by
+//! the owner only.
+//!
+//! `{config}` defaults to `~/.config/aocd` and can be overridden with `AOCD_CONFIG_DIR`,
+//! `{data}` defaults to `{config}` and can be overridden with `AOCD_DIR`.
+//!
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use super::{cache::write_secret, session::normalize_token};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocdDirs {
+    pub config: PathBuf,
+    pub data: PathBuf,
+}
+
+impl AocdDirs {
+    pub fn from_env() -> Self {
+        let home = env::var_os("HOME")
+            .or_else(|| env::var_os("USERPROFILE"))
+            .map(PathBuf::from)
+            .unwrap_or_default();
+        let config = env::var_os("AOCD_CONFIG_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(".config").join("aocd"));
+        let data = env::var_os("AOCD_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| config.clone());
+        Self { config, data }
+    }
+
+    /// Returns the token of the default account, or of the given named account
+    pub fn read_token(&self, name: Option<&str>) -> Option<String> {
+        let token = match name {
+            None => fs::read_to_string(self.config.join("token")).ok()?,
+            Some(name) => read_json_map(&self.config.join("tokens.json"))?.remove(name)?,
+        };
+        let token = normalize_token(&token);
+        (!token.is_empty()).then_some(token)
+    }
+
+    /// Stores the token of the default account, or of the given named account next to the other
+    /// named accounts
+    pub fn write_token(&self, name: Option<&str>, token: &str) -> io::Result<()> {
+        let token = normalize_token(token);
+        match name {
+            None => write_secret(&self.config.join("token"), format!("{token}\n")),
+            Some(name) => {
+                let path = self.config.join("tokens.json");
+                let mut tokens: BTreeMap<String, String> = read_json_map(&path)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect();
+                tokens.insert(name.to_string(), token);
+                write_secret(&path, serde_json::to_string_pretty(&tokens)?)
+            }
+        }
+    }
+
+    /// Returns the path `aocd` caches the input of the given puzzle under, `None` if the user id of
+    /// the token is unknown
+    pub fn input_path(&self, token: &str, year: u16, day: u8) -> Option<PathBuf> {
+        let user_id = read_json_map(&self.config.join("token2id.json"))?.remove(token)?;
+        Some(
+            self.data
+                .join(user_id)
+                .join(format!("{year}_{day:02}_input.txt")),
+        )
+    }
+}
+
+fn read_json_map(path: &Path) -> Option<HashMap<String, String>> {
+    let json = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    fn temp_dirs(name: &str) -> (TestDir, AocdDirs) {
+        let dir = TestDir::new(&format!("aocd_{name}"));
+        let dirs = AocdDirs {
+            config: dir.to_path_buf(),
+            data: dir.to_path_buf(),
+        };
+        (dir, dirs)
+    }
+
+    #[test]
+    fn aocd_tokens() {
+        let (_dir, dirs) = temp_dirs("tokens");
+        assert_eq!(dirs.read_token(None), None);
+
+        fs::write(dirs.config.join("token"), "53616c74\n").unwrap();
+        fs::write(
+            dirs.config.join("tokens.json"),
+            r#"{"alt": "session=abcd"}"#,
+        )
+        .unwrap();
+        assert_eq!(dirs.read_token(None).as_deref(), Some("53616c74"));
+        assert_eq!(dirs.read_token(Some("alt")).as_deref(), Some("abcd"));
+        assert_eq!(dirs.read_token(Some("other")), None);
+
+        dirs.write_token(None, "session=beef").unwrap();
+        dirs.write_token(Some("other"), "cafe").unwrap();
+        assert_eq!(dirs.read_token(None).as_deref(), Some("beef"));
+        assert_eq!(dirs.read_token(Some("alt")).as_deref(), Some("abcd"));
+        assert_eq!(dirs.read_token(Some("other")).as_deref(), Some("cafe"));
+        #[cfg(unix)]
+        for file in ["token", "tokens.json"] {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(dirs.config.join(file))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600, "{file}");
+        }
+    }
+
+    #[test]
+    fn aocd_input_path() {
+        let (_dir, dirs) = temp_dirs("input");
+        assert_eq!(dirs.input_path("53616c74", 2024, 9), None);
+
+        fs::write(
+            dirs.config.join("token2id.json"),
+            r#"{"53616c74": "github.alice.123"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            dirs.input_path("53616c74", 2024, 9),
+            Some(dirs.data.join("github.alice.123").join("2024_09_input.txt"))
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! batch.rs
+//!
+//! Runs the solver of a day on every input of a team, to compare solutions across personal inputs
+//!
+//! The inputs live in an input directory per day, `{cache_dir}/day{NN}/{name}.txt` by default
+//! (encrypted `{name}.txt.enc` files are read as well). An optional `{name}.answers` file holds the
+//! answers another implementation got for the same input, one `part{P}: {answer}` line per part,
+//! and is used to flag disagreements. Like inputs, recorded answers are encrypted with the cache key.
+//!
+
+use std::{
+    error::Error,
+    fs,
+    path::{Path, PathBuf},
+    time,
+};
+
+use super::{
+    cache::{read_private, write_private},
+    crypt::ENCRYPTED_EXTENSION,
+    examples::{format_answers, parse_answers},
+    Answer, DaySolver, SolveError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Result<Answer, SolveError>,
+    pub micros: u128,
+    /// The answer recorded by another implementation, if any
+    pub expected: Option<Answer>,
+}
+
+impl PartRun {
+    pub fn disagrees(&self) -> bool {
+        matches!((&self.answer, &self.expected), (Ok(a), Some(e)) if a.is_solved() && a != e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputRun {
+    pub name: String,
+    pub parse_micros: u128,
+    pub parts: Vec<PartRun>,
+}
+
+/// Lists the inputs of an input directory as `(name, path)`, sorted by name.
+/// The path of encrypted inputs is the one of their plain counterpart, as expected by [`read_private`].
+pub fn list_inputs(dir: &Path) -> Result<Vec<(String, PathBuf)>, Box<dyn Error>> {
+    let mut inputs = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let file_name = file_name
+            .strip_suffix(&format!(".{ENCRYPTED_EXTENSION}"))
+            .unwrap_or(&file_name);
+        let Some(name) = file_name.strip_suffix(".txt") else {
+            continue;
+        };
+        inputs.push((name.to_string(), dir.join(file_name)));
+    }
+    inputs.sort();
+    inputs.dedup();
+    Ok(inputs)
+}
+
+/// Runs the given number of parts of the solver on every input of the given directory.
+/// With `record`, the answers are written to `{name}.answers` for inputs which have none yet.
+pub fn run_inputs(
+    solver: &dyn DaySolver,
+    part_count: u8,
+    dir: &Path,
+    record: bool,
+) -> Result<Vec<InputRun>, Box<dyn Error>> {
+    let mut runs = vec![];
+    for (name, path) in list_inputs(dir)? {
+        let Some(input) = read_private(&path)? else {
+            continue;
+        };
+        let answers_path = dir.join(format!("{name}.answers"));
+        let expected: Option<Vec<_>> = read_private(&answers_path)?.map(|answers| {
+            parse_answers(&answers)
+                .into_iter()
+                .map(|(part, answer)| (part, Answer::parse(&answer)))
+                .collect()
+        });
+
+        let run = run_input(
+            solver,
+            part_count,
+            name,
+            &input,
+            expected.as_deref().unwrap_or_default(),
+        );
+        if record && expected.is_none() {
+            let answers: Vec<_> = run
+                .parts
+                .iter()
+                .filter_map(|run| match &run.answer {
+                    Ok(answer) if answer.is_solved() => Some((run.part, answer.to_string())),
+                    _ => None,
+                })
+                .collect();
+            write_private(&answers_path, &format_answers(&answers))?;
+        }
+        runs.push(run);
+    }
+    Ok(runs)
+}
+
+/// Runs the given number of parts of the solver on a single input, along with the answers the
+/// parts are expected to have
+pub fn run_input(
+    solver: &dyn DaySolver,
+    part_count: u8,
+    name: String,
+    input: &str,
+    expected: &[(u8, Answer)],
+) -> InputRun {
+    let timer = time::Instant::now();
+    let parsed = solver.parse_input(input);
+    let parse_micros = timer.elapsed().as_micros();
+
+    let mut parts = vec![];
+    for part in 1..=part_count {
+        let timer = time::Instant::now();
+        let answer = match (&parsed, part) {
+            (Err(e), _) => Err(e.clone()),
+            (Ok(parsed), 1) => parsed.part1(),
+            (Ok(parsed), _) => parsed.part2(),
+        };
+        let micros = timer.elapsed().as_micros();
+        let expected = expected
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.clone());
+        parts.push(PartRun {
+            part,
+            answer,
+            micros,
+            expected,
+        });
+    }
+    InputRun {
+        name,
+        parse_micros,
+        parts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::{test_dir::TestDir, Solution};
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> Result<usize, SolveError> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(&self, lines: &usize) -> Result<Answer, SolveError> {
+            Ok((*lines).into())
+        }
+
+        fn part2(&self, _lines: &usize) -> Result<Answer, SolveError> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    #[test]
+    fn run_inputs_flags_disagreements() {
+        let dir = TestDir::new("batch");
+        fs::write(dir.join("alice.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("alice.answers"), "part1: 2\n").unwrap();
+        fs::write(dir.join("bob.txt"), "1\n2\n3\n").unwrap();
+        fs::write(dir.join("bob.answers"), "part1: 4\npart2: 7\n").unwrap();
+        fs::write(dir.join("carol.txt"), "1\n").unwrap();
+
+        let runs = run_inputs(&Lines, 2, &dir, true).unwrap();
+        let names: Vec<_> = runs.iter().map(|run| run.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob", "carol"]);
+        assert!(!runs[0].parts.iter().any(PartRun::disagrees));
+        assert!(runs[1].parts[0].disagrees());
+        assert!(!runs[1].parts[1].disagrees());
+        assert_eq!(runs[2].parts[0].expected, None);
+        assert_eq!(
+            fs::read_to_string(dir.join("carol.answers")).unwrap(),
+            "part1: 1\n"
+        );
+    }
+}
@@ -0,0 +1,336 @@
+//! cache.rs
+//!
+//! Reading and writing of the files in the cache directory
+//!
+//! Every cached input `day{NN}.txt` comes with a `day{NN}.meta.json` record
+//! holding the fetch time, byte length, SHA-256 hash and profile of the input.
+//! Cached inputs are checked against it before use.
+//!
+//! If a cache key is configured (see [`super::crypt`]), inputs are stored encrypted
+//! as `day{NN}.txt.enc` instead and transparently decrypted when read.
+//!
+
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{
+    crypt::{cache_key, encrypted_path},
+    session::profile_dir,
+    AoCError, Utc,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputMeta {
+    /// RFC 3339 timestamp of when the input was fetched
+    pub fetched_at: String,
+    pub length: usize,
+    pub sha256: String,
+    pub profile: String,
+}
+
+impl InputMeta {
+    pub fn new(input: &str, profile: &str) -> Self {
+        Self {
+            fetched_at: Utc::now().to_rfc3339(),
+            length: input.len(),
+            sha256: sha256_hex(input.as_bytes()),
+            profile: profile.to_string(),
+        }
+    }
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// The metadata record belonging to the given cached input
+pub fn meta_path(input_path: &Path) -> PathBuf {
+    input_path.with_extension("meta.json")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Integrity {
+    Valid,
+    Empty,
+    LengthMismatch { expected: usize, actual: usize },
+    HashMismatch,
+}
+
+impl fmt::Display for Integrity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Valid => write!(f, "valid"),
+            Self::Empty => write!(f, "empty"),
+            Self::LengthMismatch { expected, actual } => {
+                write!(f, "expected {expected} bytes, found {actual}")
+            }
+            Self::HashMismatch => write!(f, "content hash does not match"),
+        }
+    }
+}
+
+/// Checks a cached input against its metadata record.
+/// Inputs without record (e.g. placed by hand) are only checked for being empty.
+pub fn check_input(path: &Path, input: &str) -> Integrity {
+    if input.trim().is_empty() {
+        return Integrity::Empty;
+    }
+    let Some(meta) = read_meta(path) else {
+        return Integrity::Valid;
+    };
+    if meta.length != input.len() {
+        return Integrity::LengthMismatch {
+            expected: meta.length,
+            actual: input.len(),
+        };
+    }
+    if meta.sha256 != sha256_hex(input.as_bytes()) {
+        return Integrity::HashMismatch;
+    }
+    Integrity::Valid
+}
+
+/// Reads a cached input or other private file like stored answers, decrypting its `.enc`
+/// counterpart if there is no plain file. Returns `None` if neither exists.
+pub fn read_private(path: &Path) -> Result<Option<String>, Box<dyn Error>> {
+    match fs::read_to_string(path) {
+        Ok(input) => return Ok(Some(input)),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+        Err(_) => {}
+    }
+    let encrypted = match fs::read(encrypted_path(path)) {
+        Ok(encrypted) => encrypted,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let key = cache_key().ok_or(AoCError::MissingKey)?;
+    Ok(Some(key.decrypt(&encrypted)?))
+}
+
+/// Returns whether the given input is cached and passes [`check_input`]. Fails if the input is
+/// cached but cannot be read, e.g. when it is encrypted and no cache key is configured.
+#[cfg(any(feature = "fetch", test))]
+pub fn is_valid_input(path: &Path) -> Result<bool, Box<dyn Error>> {
+    Ok(read_private(path)?.is_some_and(|input| check_input(path, &input) == Integrity::Valid))
+}
+
+/// Atomically writes the given contents to a cache file, creating its directory if needed.
+/// The contents are written to a temporary file first, which is then renamed.
+pub fn write_cache(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    write_atomic(path, contents.as_ref(), &OpenOptions::new())
+}
+
+/// Atomically writes a credential like a session token, see [`write_cache`]. The file is created
+/// readable by the owner only.
+pub fn write_secret(path: &Path, contents: impl AsRef<[u8]>) -> io::Result<()> {
+    let mut options = OpenOptions::new();
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    write_atomic(path, contents.as_ref(), &options)
+}
+
+fn write_atomic(path: &Path, contents: &[u8], options: &OpenOptions) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    // A leftover of an interrupted write would keep its permissions
+    remove_stale(&tmp)?;
+    options
+        .clone()
+        .write(true)
+        .create_new(true)
+        .open(&tmp)?
+        .write_all(contents)?;
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+/// Writes a private file like an input or stored answers to the cache. It is encrypted if a cache
+/// key is configured, and stale copies of the other form are removed. Without a cache key, an
+/// encrypted copy is never replaced, as it may be committed.
+pub fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    let encrypted = encrypted_path(path);
+    match cache_key() {
+        Some(key) => {
+            write_cache(&encrypted, key.encrypt(contents))?;
+            remove_stale(path)
+        }
+        None if encrypted.exists() => Err(io::Error::other(AoCError::MissingKey)),
+        None => write_cache(path, contents),
+    }
+}
+
+/// Writes the given input to the cache along with its metadata record, see [`write_private`]
+pub fn write_input(path: &Path, input: &str, profile: &str) -> io::Result<()> {
+    write_private(path, input)?;
+    let meta = serde_json::to_string_pretty(&InputMeta::new(input, profile))?;
+    write_cache(&meta_path(path), &meta)
+}
+
+fn remove_stale(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+pub fn read_meta(input_path: &Path) -> Option<InputMeta> {
+    let json = fs::read_to_string(meta_path(input_path)).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Moves the files of the former flat layout (`inputs/day{N}.txt`, `inputs/{profile}/day{N}.txt`)
+/// into the `{root}/[{profile}/]{year}/day{NN}.txt` layout and records metadata for the inputs.
+/// Returns the moved files.
+pub fn migrate_flat_cache(
+    from: &Path,
+    cache_root: &Path,
+    year: u16,
+) -> io::Result<Vec<(PathBuf, PathBuf)>> {
+    let mut dirs = vec![(from.to_path_buf(), None)];
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if entry.file_type()?.is_dir() && name.parse::<u16>().is_err() {
+            dirs.push((entry.path(), Some(name)));
+        }
+    }
+
+    let day_file = Regex::new(r"^day(\d+)\.(txt|md|html)$").unwrap();
+    let leaderboard_file = Regex::new(r"^leaderboard\w+\.json$").unwrap();
+
+    let mut moved = vec![];
+    for (dir, profile) in dirs {
+        let target_dir = profile_dir(cache_root, profile.as_deref()).join(year.to_string());
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if !path.is_file() {
+                continue;
+            }
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let target = if let Some(c) = day_file.captures(&name) {
+                let day: u8 = c[1].parse().unwrap_or(0);
+                target_dir.join(format!("day{:02}.{}", day, &c[2]))
+            } else if leaderboard_file.is_match(&name) {
+                target_dir.join(name.as_ref())
+            } else {
+                continue;
+            };
+            if target == path || target.exists() {
+                continue;
+            }
+
+            fs::create_dir_all(&target_dir)?;
+            if fs::rename(&path, &target).is_err() {
+                fs::copy(&path, &target)?;
+                fs::remove_file(&path)?;
+            }
+            if target.extension().is_some_and(|ext| ext == "txt") {
+                let input = fs::read_to_string(&target)?;
+                let meta = InputMeta::new(&input, profile.as_deref().unwrap_or("default"));
+                write_cache(&meta_path(&target), &serde_json::to_string_pretty(&meta)?)?;
+            }
+            moved.push((path, target));
+        }
+    }
+    moved.sort();
+    Ok(moved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    #[test]
+    fn input_meta_record() {
+        let dir = TestDir::new("cache_meta");
+        let path = dir.join("2024").join("day01.txt");
+        write_input(&path, "3   4\n", "default").unwrap();
+
+        let meta = read_meta(&path).unwrap();
+        assert_eq!(meta.length, 6);
+        assert_eq!(meta.sha256, sha256_hex(b"3   4\n"));
+        assert_eq!(meta.profile, "default");
+        assert!(dir.join("2024").join("day01.meta.json").exists());
+    }
+
+    #[test]
+    fn check_input_integrity() {
+        let dir = TestDir::new("cache_check");
+        let path = dir.join("day02.txt");
+        write_input(&path, "7 6 4 2 1\n", "default").unwrap();
+        assert!(!dir.join("day02.txt.tmp").exists());
+        assert!(is_valid_input(&path).unwrap());
+
+        assert_eq!(
+            check_input(&path, "7 6 4"),
+            Integrity::LengthMismatch {
+                expected: 10,
+                actual: 5
+            }
+        );
+        assert_eq!(check_input(&path, "7 6 4 2 9\n"), Integrity::HashMismatch);
+        assert_eq!(check_input(&path, "\n"), Integrity::Empty);
+
+        fs::write(&path, "").unwrap();
+        assert!(!is_valid_input(&path).unwrap());
+        assert!(!is_valid_input(&dir.join("day03.txt")).unwrap());
+    }
+
+    #[test]
+    fn encrypted_input_without_key() {
+        if cache_key().is_some() {
+            return;
+        }
+        let dir = TestDir::new("cache_locked");
+        let path = dir.join("day04.txt");
+        write_cache(&encrypted_path(&path), b"AOCENC2").unwrap();
+
+        assert!(is_valid_input(&path).is_err());
+        assert!(write_private(&path, "MMMSXXMASM\n").is_err());
+        assert!(encrypted_path(&path).exists());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn migrate_flat_layout() {
+        let dir = TestDir::new("cache_migrate");
+        fs::create_dir_all(dir.join("alt")).unwrap();
+        fs::write(dir.join("day1.txt"), "1").unwrap();
+        fs::write(dir.join("day12.md"), "## Day 12").unwrap();
+        fs::write(dir.join("leaderboard42.json"), "{}").unwrap();
+        fs::write(dir.join(".gitignore"), "*.txt").unwrap();
+        fs::write(dir.join("alt").join("day3.txt"), "3").unwrap();
+
+        let moved = migrate_flat_cache(&dir, &dir, 2024).unwrap();
+        assert_eq!(moved.len(), 4);
+        assert!(dir.join("2024/day01.txt").exists());
+        assert!(dir.join("2024/day01.meta.json").exists());
+        assert!(dir.join("2024/day12.md").exists());
+        assert!(dir.join("2024/leaderboard42.json").exists());
+        assert!(dir.join(".gitignore").exists());
+        assert_eq!(
+            read_meta(&dir.join("alt/2024/day03.txt")).unwrap().profile,
+            "alt"
+        );
+        assert!(!dir.join("day1.txt").exists());
+    }
+}
@@ -0,0 +1,85 @@
+//! compare.rs
+//!
+//! Runs every registered variant of a day on the same input, to check optimized implementations
+//! against their reference
+//!
+//! The first variant is the reference: the answers of every other variant are expected to match
+//! its solved answers, and are flagged otherwise.
+//!
+
+use super::{
+    batch::{run_input, InputRun},
+    Answer, DaySolver,
+};
+
+/// Runs the given number of parts of every variant on the input, the reference variant first
+pub fn run_variants(
+    variants: &[(&str, Box<dyn DaySolver>)],
+    part_count: u8,
+    input: &str,
+) -> Vec<InputRun> {
+    let mut runs: Vec<InputRun> = vec![];
+    for (name, solver) in variants {
+        let expected: Vec<(u8, Answer)> = runs
+            .first()
+            .into_iter()
+            .flat_map(|reference| &reference.parts)
+            .filter_map(|run| match &run.answer {
+                Ok(answer) if answer.is_solved() => Some((run.part, answer.clone())),
+                _ => None,
+            })
+            .collect();
+        runs.push(run_input(
+            solver.as_ref(),
+            part_count,
+            name.to_string(),
+            input,
+            &expected,
+        ));
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{PartRun, Solution, SolveError};
+
+    /// Sums the numbers of the input, with an off by one error in part 2 when `buggy`
+    struct Sum {
+        buggy: bool,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Vec<u32>, SolveError> {
+            Ok(input.lines().filter_map(|l| l.parse().ok()).collect())
+        }
+
+        fn part1(&self, numbers: &Vec<u32>) -> Result<Answer, SolveError> {
+            Ok(numbers.iter().sum::<u32>().into())
+        }
+
+        fn part2(&self, numbers: &Vec<u32>) -> Result<Answer, SolveError> {
+            Ok((numbers.iter().sum::<u32>() + u32::from(self.buggy)).into())
+        }
+    }
+
+    #[test]
+    fn run_variants_flags_disagreements() {
+        let variants: Vec<(&str, Box<dyn DaySolver>)> = vec![
+            ("default", Box::new(Sum { buggy: false })),
+            ("fast", Box::new(Sum { buggy: false })),
+            ("buggy", Box::new(Sum { buggy: true })),
+        ];
+        let runs = run_variants(&variants, 2, "1\n2\n3\n");
+        let names: Vec<_> = runs.iter().map(|run| run.name.as_str()).collect();
+        assert_eq!(names, ["default", "fast", "buggy"]);
+        assert!(runs[0].parts.iter().all(|run| run.expected.is_none()));
+        assert!(!runs[1].parts.iter().any(PartRun::disagrees));
+        assert!(!runs[2].parts[0].disagrees());
+        assert!(runs[2].parts[1].disagrees());
+        assert_eq!(runs[2].parts[1].expected, Some(Answer::from(6_u32)));
+    }
+}
@@ -0,0 +1,216 @@
+//! crypt.rs
+//!
+//! Optional at-rest encryption of cached inputs and stored answers, so they can
+//! be committed without publishing them
+//!
+//! The key is derived from a passphrase taken from the `AOC_CACHE_KEY` env
+//! variable, or from the file named by `AOC_CACHE_KEY_FILE`. Encrypted files are
+//! stored next to their plain counterpart with an additional `.enc` extension.
+//!
+//! Keys are derived with Argon2id and a random salt, stored in the header of every
+//! encrypted file: `AOCENC2 | salt | nonce | ciphertext`. So committed files cannot
+//! be brute-forced with precomputed tables, and every guess is expensive.
+//!
+
+use std::{
+    collections::HashMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{Aead, Generate, KeyInit},
+    Key, XChaCha20Poly1305, XNonce,
+};
+
+use super::{answer_cache::ANSWERS_FILE, cache::write_cache, AoCError};
+
+const KEY_VAR: &str = "AOC_CACHE_KEY";
+const KEY_FILE_VAR: &str = "AOC_CACHE_KEY_FILE";
+const MAGIC: &[u8] = b"AOCENC2";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+static KEY: OnceLock<Option<CacheKey>> = OnceLock::new();
+
+type Salt = [u8; SALT_LEN];
+
+/// A passphrase together with the keys derived from it during this run
+pub struct CacheKey {
+    passphrase: String,
+    /// The salt and key of the files encrypted during this run, each with its own nonce
+    own: OnceLock<(Salt, Key)>,
+    /// The keys of the salts found in encrypted files
+    derived: Mutex<HashMap<Salt, Key>>,
+}
+
+impl CacheKey {
+    pub fn from_passphrase(passphrase: &str) -> Self {
+        Self {
+            passphrase: passphrase.trim().to_string(),
+            own: OnceLock::new(),
+            derived: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn derive(&self, salt: &Salt) -> Key {
+        let mut key = Key::default();
+        Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), salt, &mut key)
+            .expect("valid Argon2 salt and output lengths");
+        key
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> Vec<u8> {
+        let (salt, key) = self.own.get_or_init(|| {
+            let salt = Salt::generate();
+            (salt, self.derive(&salt))
+        });
+        let cipher = XChaCha20Poly1305::new(key);
+        let nonce = XNonce::generate();
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .expect("encryption of an in-memory buffer");
+        [MAGIC, salt, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<String, AoCError> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|d| d.len() >= SALT_LEN + NONCE_LEN)
+            .ok_or(AoCError::DecryptionFailed)?;
+        let (salt, data) = data.split_at(SALT_LEN);
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let salt = Salt::try_from(salt).map_err(|_| AoCError::DecryptionFailed)?;
+        let nonce = XNonce::try_from(nonce).map_err(|_| AoCError::DecryptionFailed)?;
+
+        let key = *self
+            .derived
+            .lock()
+            .expect("no panic while deriving")
+            .entry(salt)
+            .or_insert_with(|| self.derive(&salt));
+        let cipher = XChaCha20Poly1305::new(&key);
+        let plaintext = cipher
+            .decrypt(&nonce, ciphertext)
+            .map_err(|_| AoCError::DecryptionFailed)?;
+        String::from_utf8(plaintext).map_err(|_| AoCError::DecryptionFailed)
+    }
+}
+
+/// Returns the key configured by `AOC_CACHE_KEY` or `AOC_CACHE_KEY_FILE`, if any
+pub fn cache_key() -> Option<&'static CacheKey> {
+    KEY.get_or_init(|| {
+        let passphrase = env::var(KEY_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(env::var_os(KEY_FILE_VAR)?).ok())?;
+        (!passphrase.trim().is_empty()).then(|| CacheKey::from_passphrase(&passphrase))
+    })
+    .as_ref()
+}
+
+/// The encrypted counterpart of the given cache file
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(".");
+    encrypted.push(ENCRYPTED_EXTENSION);
+    PathBuf::from(encrypted)
+}
+
+/// Whether the given cache file is private: an input, the answers recorded for a batch input or
+/// the answer cache
+fn is_private(path: &Path) -> bool {
+    let extension = path.extension().unwrap_or_default();
+    extension == "txt" || extension == "answers" || path.ends_with(ANSWERS_FILE)
+}
+
+/// Encrypts (or decrypts) every private file below the given directory in place.
+/// Returns the files which were converted.
+pub fn convert_cache(dir: &Path, key: &CacheKey, encrypt: bool) -> io::Result<Vec<PathBuf>> {
+    let mut converted = vec![];
+    if !dir.exists() {
+        return Ok(converted);
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            converted.extend(convert_cache(&path, key, encrypt)?);
+            continue;
+        }
+        let extension = path.extension().unwrap_or_default();
+        match encrypt {
+            true if is_private(&path) => {
+                let input = fs::read_to_string(&path)?;
+                write_cache(&encrypted_path(&path), key.encrypt(&input))?;
+                fs::remove_file(&path)?;
+            }
+            false if extension == ENCRYPTED_EXTENSION => {
+                let input = key
+                    .decrypt(&fs::read(&path)?)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                write_cache(&path.with_extension(""), input)?;
+                fs::remove_file(&path)?;
+            }
+            _ => continue,
+        }
+        converted.push(path);
+    }
+    converted.sort();
+    Ok(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let key = CacheKey::from_passphrase("correct horse battery staple");
+        let encrypted = key.encrypt("3   4\n4   3\n");
+        assert!(encrypted.starts_with(MAGIC));
+        assert_ne!(key.encrypt("3   4\n4   3\n"), encrypted);
+        assert_eq!(key.decrypt(&encrypted).unwrap(), "3   4\n4   3\n");
+
+        // The salt is read from the header, so another run derives the same key
+        let again = CacheKey::from_passphrase("correct horse battery staple");
+        assert_eq!(again.decrypt(&encrypted).unwrap(), "3   4\n4   3\n");
+        assert_ne!(
+            again.encrypt("1")[..MAGIC.len() + SALT_LEN],
+            encrypted[..MAGIC.len() + SALT_LEN]
+        );
+
+        let other = CacheKey::from_passphrase("wrong");
+        assert!(matches!(
+            other.decrypt(&encrypted),
+            Err(AoCError::DecryptionFailed)
+        ));
+        assert!(key.decrypt(b"3   4").is_err());
+    }
+
+    #[test]
+    fn convert_cache_both_ways() {
+        let dir = TestDir::new("crypt");
+        fs::create_dir_all(dir.join("2024")).unwrap();
+        fs::write(dir.join("2024/day01.txt"), "1").unwrap();
+        fs::write(dir.join("2024/day01.meta.json"), "{}").unwrap();
+        fs::write(dir.join("2024/answers.json"), "{}").unwrap();
+
+        let key = CacheKey::from_passphrase("secret");
+        let converted = convert_cache(&dir, &key, true).unwrap();
+        assert_eq!(
+            converted,
+            vec![dir.join("2024/answers.json"), dir.join("2024/day01.txt")]
+        );
+        assert!(!dir.join("2024/day01.txt").exists());
+        assert!(dir.join("2024/day01.txt.enc").exists());
+        assert!(dir.join("2024/day01.meta.json").exists());
+
+        convert_cache(&dir, &key, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("2024/day01.txt")).unwrap(), "1");
+        assert!(!dir.join("2024/day01.txt.enc").exists());
+    }
+}
@@ -1,4 +1,142 @@
+use super::{normalize_input, Answer, Param, Params, SolveError};
+
+/// A puzzle solution with a parse step whose result is shared by both parts
+pub trait Solution {
+    type Input;
+
+    /// The named parameters the solution is built with, see [`super::Params`]
+    const PARAMS: &'static [Param] = &[];
+    /// The number of puzzle parts, the last day of an event only has one
+    const PARTS: u8 = 2;
+    /// Whether [`parse`](Self::parse) gets the input normalized by [`normalize_input`]. Opt out for
+    /// puzzles where line endings or trailing whitespace matter.
+    const NORMALIZE: bool = true;
+    /// The version of the solution, bump it on every change affecting the answers. Cached answers
+    /// are keyed by it, or by the hash of the build when the solution declares none.
+    const VERSION: Option<u32> = None;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Only called for solutions with two [`PARTS`](Self::PARTS)
+    fn part2(&self, _input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::Unsolved)
+    }
+}
+
+/// Object-safe adapter of [`Solution`], so solvers of different input types can be boxed together
 pub trait DaySolver {
-    fn part1(&self, input: &str) -> Option<String>;
-    fn part2(&self, input: &str) -> Option<String>;
+    fn params(&self) -> &'static [Param];
+    fn parts(&self) -> u8;
+    fn version(&self) -> Option<u32>;
+    fn parse_input<'a>(&'a self, input: &str) -> Result<Box<dyn ParsedInput + 'a>, SolveError>;
+}
+
+/// A parsed input together with the solution it belongs to
+pub trait ParsedInput {
+    fn part1(&self) -> Result<Answer, SolveError>;
+    fn part2(&self) -> Result<Answer, SolveError>;
+}
+
+struct Parsed<'a, S: Solution> {
+    solution: &'a S,
+    input: S::Input,
+}
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    fn part1(&self) -> Result<Answer, SolveError> {
+        self.solution.part1(&self.input)
+    }
+
+    fn part2(&self) -> Result<Answer, SolveError> {
+        self.solution.part2(&self.input)
+    }
+}
+
+impl<S: Solution> DaySolver for S {
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn version(&self) -> Option<u32> {
+        S::VERSION
+    }
+
+    fn parse_input<'a>(&'a self, input: &str) -> Result<Box<dyn ParsedInput + 'a>, SolveError> {
+        let input = match S::NORMALIZE {
+            true => normalize_input(input),
+            false => input.into(),
+        };
+        Ok(Box::new(Parsed {
+            solution: self,
+            input: self.parse(&input)?,
+        }))
+    }
+}
+
+/// A solver registered with [`register_day!`](crate::register_day)
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// The name of an alternative implementation of the day, `None` for the default one
+    pub variant: Option<&'static str>,
+    pub parts: u8,
+    pub params: &'static [Param],
+    pub build: fn(&Params) -> Box<dyn DaySolver>,
+}
+
+inventory::collect!(Registration);
+
+impl Registration {
+    pub fn solver(&self, params: &Params) -> Box<dyn DaySolver> {
+        (self.build)(params)
+    }
+
+    pub fn variant_name(&self) -> &'static str {
+        self.variant.unwrap_or("default")
+    }
+}
+
+/// Registers the solution of a day together with the puzzle title.
+///
+/// Solutions with parameters are built through their `new(&Params)` constructor. Alternative
+/// implementations of a day, e.g. an optimized one next to the reference, are registered as named
+/// variants:
+/// ```ignore
+/// register_day!(2024, 1, "Historian Hysteria", Day1);
+/// register_day!(2024, 14, "Restroom Redoubt", Day14::new);
+/// register_day!(2024, 9, "Disk Fragmenter", Day9Spans, variant = "spans");
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    ($year:literal, $day:literal, $title:literal, $solution:ident::new $(, variant = $variant:literal)?) => {
+        $crate::register_day!(@submit $year, $day, $title, $solution, |params| $solution::new(params), $($variant)?);
+    };
+    ($year:literal, $day:literal, $title:literal, $solution:ident $(, variant = $variant:literal)?) => {
+        $crate::register_day!(@submit $year, $day, $title, $solution, |_| $solution, $($variant)?);
+    };
+    (@submit $year:literal, $day:literal, $title:literal, $solution:ident, $build:expr, $($variant:literal)?) => {
+        ::inventory::submit! {
+            $crate::utils::Registration {
+                year: $year,
+                day: $day,
+                title: $title,
+                variant: $crate::register_day!(@variant $($variant)?),
+                parts: <$solution as $crate::utils::Solution>::PARTS,
+                params: <$solution as $crate::utils::Solution>::PARAMS,
+                build: |params| Box::new(($build)(params)),
+            }
+        }
+    };
+    (@variant) => {
+        None
+    };
+    (@variant $variant:literal) => {
+        Some($variant)
+    };
 }
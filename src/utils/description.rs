@@ -0,0 +1,211 @@
+//! description.rs
+//!
+//! Converts AoC puzzle descriptions to Markdown and caches them
+//!
+
+use std::{error::Error, fs};
+
+use super::{
+    cache::write_cache,
+    fetch::{fetch_description, is_offline, AOC_FQDN},
+    html::{day_desc_articles, tokenize, Token},
+    is_puzzle_available,
+    session::active_profile,
+    AoCError,
+};
+
+const PART_TWO_HEADER: &str = "--- Part Two ---";
+
+/// Returns the HTML puzzle page of the given day of the given year.
+///
+/// The page is cached as `day{NN}.html` in the cache directory of the active profile. As long as the cached
+/// copy lacks part 2 and a session is available, the page is fetched again so
+/// part 2 shows up once part 1 has been solved.
+pub async fn get_puzzle_page(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+    let filename = active_profile().day_path(year, day, "html");
+    let cached = fs::read_to_string(&filename).ok();
+
+    let complete = cached.as_ref().is_some_and(|c| c.contains(PART_TWO_HEADER));
+    let can_refresh = cached.is_none() || (active_profile().token.is_some() && !is_offline());
+    if complete || !can_refresh || !is_puzzle_available(year, day) {
+        return cached.ok_or_else(|| AoCError::NoDescription.into());
+    }
+
+    let html = match fetch_description(year, day).await {
+        Ok(html) => html,
+        Err(e) if cached.is_none() => return Err(e),
+        Err(_) => return cached.ok_or_else(|| AoCError::NoDescription.into()),
+    };
+    if day_desc_articles(&tokenize(&html)).is_empty() {
+        return cached.ok_or_else(|| AoCError::NoDescription.into());
+    }
+
+    write_cache(&filename, &html)?;
+    Ok(html)
+}
+
+/// Returns the Markdown description of the given day, cached as `day{NN}.md` next to the page.
+/// Falls back to the cached Markdown if the puzzle page cannot be retrieved.
+pub async fn get_description(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+    let filename = active_profile().day_path(year, day, "md");
+    let html = match get_puzzle_page(year, day).await {
+        Ok(html) => html,
+        Err(e) => return fs::read_to_string(&filename).map_err(|_| e),
+    };
+
+    let markdown = html_to_markdown(&html).ok_or(AoCError::NoDescription)?;
+    write_cache(&filename, &markdown)?;
+    Ok(markdown)
+}
+
+/// Converts every `<article class="day-desc">` of a puzzle page to Markdown.
+/// Returns `None` if the page does not contain any description.
+pub fn html_to_markdown(html: &str) -> Option<String> {
+    let tokens = tokenize(html);
+    let articles = day_desc_articles(&tokens);
+    if articles.is_empty() {
+        return None;
+    }
+
+    let mut raw = String::new();
+    for article in articles {
+        article_to_markdown(article, &mut raw);
+    }
+
+    let mut md = String::new();
+    let mut in_code_block = false;
+    for line in raw.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        md.push_str(if in_code_block { line } else { line.trim_end() });
+        md.push('\n');
+    }
+    while md.contains("\n\n\n") {
+        md = md.replace("\n\n\n", "\n\n");
+    }
+    Some(format!("{}\n", md.trim()))
+}
+
+fn article_to_markdown(article: &[Token], md: &mut String) {
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut list_depth: usize = 0;
+    let mut links = vec![];
+
+    for token in article {
+        match token {
+            Token::Open { name, .. } => match name.as_str() {
+                "h2" => md.push_str("\n\n## "),
+                "pre" => {
+                    in_pre = true;
+                    md.push_str("\n\n```\n");
+                }
+                "code" if !in_pre => {
+                    in_code = true;
+                    md.push('`');
+                }
+                "em" if !in_pre && !in_code => md.push('*'),
+                "ul" => list_depth += 1,
+                "li" => {
+                    md.push('\n');
+                    md.push_str(&"  ".repeat(list_depth.saturating_sub(1)));
+                    md.push_str("- ");
+                }
+                "a" => {
+                    let href = token.attr("href").unwrap_or_default();
+                    let href = match href.starts_with('/') {
+                        true => format!("{AOC_FQDN}{href}"),
+                        false => href.to_string(),
+                    };
+                    links.push(href);
+                    md.push('[');
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" => md.push_str("\n\n"),
+                "p" if list_depth == 0 => md.push_str("\n\n"),
+                "pre" => {
+                    in_pre = false;
+                    if !md.ends_with('\n') {
+                        md.push('\n');
+                    }
+                    md.push_str("```\n\n");
+                }
+                "code" if !in_pre => {
+                    in_code = false;
+                    md.push('`');
+                }
+                "em" if !in_pre && !in_code => md.push('*'),
+                "ul" => {
+                    list_depth = list_depth.saturating_sub(1);
+                    if list_depth == 0 {
+                        md.push_str("\n\n");
+                    }
+                }
+                "a" => {
+                    let href = links.pop().unwrap_or_default();
+                    md.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            },
+            Token::Text(text) if in_pre => md.push_str(text),
+            Token::Text(text) => {
+                let text = text.replace('\n', " ");
+                if md.is_empty() || md.ends_with('\n') || md.ends_with("- ") {
+                    md.push_str(text.trim_start());
+                } else {
+                    md.push_str(&text);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn html_to_markdown_converts_article() {
+        let html = indoc! {r#"
+            <main>
+            <article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief</em> is <a href="/2024/about">missing</a>.</p>
+            <ul>
+            <li>The first pair is <code>3</code>.</li>
+            </ul>
+            <pre><code>3   4
+            4   3
+            </code></pre>
+            <p>What is the total <span title="x">distance</span>? <code><em>11</em></code></p>
+            </article>
+            <p>Your puzzle answer was <code>1</code>.</p>
+            </main>
+        "#};
+        let md = html_to_markdown(html).unwrap();
+        assert_eq!(
+            md,
+            indoc! {"
+                ## --- Day 1: Historian Hysteria ---
+
+                The *Chief* is [missing](https://adventofcode.com/2024/about).
+
+                - The first pair is `3`.
+
+                ```
+                3   4
+                4   3
+                ```
+
+                What is the total distance? `11`
+            "}
+        );
+    }
+
+    #[test]
+    fn html_to_markdown_without_article() {
+        assert_eq!(html_to_markdown("<p>Please log in.</p>"), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! event.rs
+//!
+//! Metadata of the yearly events: the days of the calendar and the days with a single puzzle part
+//!
+//! Up to 2024, an event ran from December 1st to 25th. Since 2025, it runs for 12 days. The last
+//! day of an event only has one part: its second star is awarded for free once every other star of
+//! the event has been collected.
+//!
+
+use std::{collections::BTreeMap, ops::RangeInclusive};
+
+/// The calendar of an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Event {
+    pub year: u16,
+    pub first_day: u8,
+    pub last_day: u8,
+    /// The days with a single puzzle part
+    pub single_part_days: &'static [u8],
+}
+
+/// The calendar of the event of the given year
+pub fn event(year: u16) -> Event {
+    match year {
+        ..=2024 => Event {
+            year,
+            first_day: 1,
+            last_day: 25,
+            single_part_days: &[25],
+        },
+        _ => Event {
+            year,
+            first_day: 1,
+            last_day: 12,
+            single_part_days: &[12],
+        },
+    }
+}
+
+impl Event {
+    pub fn days(&self) -> RangeInclusive<u8> {
+        self.first_day..=self.last_day
+    }
+
+    pub fn has_day(&self, day: u8) -> bool {
+        self.days().contains(&day)
+    }
+
+    /// The number of puzzle parts of the given day
+    pub fn parts(&self, day: u8) -> u8 {
+        match self.single_part_days.contains(&day) {
+            true => 1,
+            false => 2,
+        }
+    }
+
+    /// Every day awards two stars, including the free star of single-part days
+    pub fn max_stars(&self) -> u32 {
+        2 * self.days().len() as u32
+    }
+
+    /// The stars earned by the given number of solved parts per day. A single-part day awards its
+    /// free star once every other part of the event is solved.
+    pub fn stars(&self, solved: &BTreeMap<u8, u8>) -> u32 {
+        let solved_parts = |day| solved.get(&day).copied().unwrap_or(0).min(self.parts(day));
+        let parts: u32 = self.days().map(|day| u32::from(solved_parts(day))).sum();
+        let all_solved = self.days().all(|day| solved_parts(day) == self.parts(day));
+        let free = match all_solved {
+            true => self.single_part_days.len() as u32,
+            false => 0,
+        };
+        parts + free
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_calendars() {
+        let e2024 = event(2024);
+        assert_eq!(e2024.days(), 1..=25);
+        assert_eq!(e2024.parts(24), 2);
+        assert_eq!(e2024.parts(25), 1);
+
+        let e2025 = event(2025);
+        assert!(e2025.has_day(12));
+        assert!(!e2025.has_day(13));
+        assert!(!e2025.has_day(0));
+        assert_eq!(e2025.parts(12), 1);
+        assert_eq!(e2025.max_stars(), 24);
+        assert_eq!(
+            event(2015),
+            Event {
+                year: 2015,
+                ..e2024
+            }
+        );
+    }
+
+    #[test]
+    fn free_star_of_the_last_day() {
+        let event = event(2025);
+        let mut solved: BTreeMap<u8, u8> = (1..=11).map(|day| (day, 2)).collect();
+        assert_eq!(event.stars(&solved), 22);
+
+        solved.insert(12, 1);
+        assert_eq!(event.stars(&solved), 24);
+
+        solved.insert(3, 1);
+        assert_eq!(event.stars(&solved), 22);
+    }
+}
@@ -0,0 +1,237 @@
+//! examples.rs
+//!
+//! Extracts the example inputs and their answers from AoC puzzle pages and
+//! stores them as test fixtures under `fixtures/{year}/day{N}/`
+//!
+//! Every example is made of two files:
+//! * `example{K}.txt` - The example input
+//! * `example{K}.answers` - One `part{P}: {answer}` line per part the example applies to
+//!
+
+use std::{fs, io, path::PathBuf};
+
+use super::{
+    html::{day_desc_articles, tokenize, Token},
+    project_dir,
+};
+
+/// The directory holding the fixtures, `fixtures/` of the project directory
+pub fn fixtures_dir() -> PathBuf {
+    project_dir().join("fixtures")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answers: Vec<(u8, String)>,
+}
+
+/// Picks the example inputs and expected answers out of a puzzle page.
+///
+/// Per part, the example input is the first `<pre><code>` block introduced by
+/// a paragraph mentioning an example and the answer is the last emphasized
+/// code snippet. A part without its own example block reuses the previous one.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let tokens = tokenize(html);
+    let mut examples: Vec<Example> = vec![];
+
+    for (idx, article) in day_desc_articles(&tokens).into_iter().enumerate() {
+        let part = idx as u8 + 1;
+        let (input, answer) = scan_article(article);
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        match (input, examples.last_mut()) {
+            (Some(input), Some(last)) if last.input == input => last.answers.push((part, answer)),
+            (None, Some(last)) => last.answers.push((part, answer)),
+            (Some(input), _) => examples.push(Example {
+                input,
+                answers: vec![(part, answer)],
+            }),
+            (None, None) => {}
+        }
+    }
+    examples
+}
+
+fn scan_article(article: &[Token]) -> (Option<String>, Option<String>) {
+    let mut blocks = vec![];
+    let mut answer = None;
+
+    let mut paragraph = String::new();
+    let mut block: Option<String> = None;
+    let mut code_depth = 0;
+    let mut em_depth = 0;
+    let mut snippet = String::new();
+
+    for token in article {
+        match token {
+            Token::Open { name, .. } => match name.as_str() {
+                "p" => paragraph.clear(),
+                "pre" => block = Some(String::new()),
+                "code" => code_depth += 1,
+                "em" => em_depth += 1,
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "pre" => {
+                    if let Some(block) = block.take() {
+                        blocks.push((paragraph.to_lowercase(), block));
+                    }
+                }
+                "code" | "em" => {
+                    if code_depth > 0 && em_depth > 0 && block.is_none() {
+                        answer = Some(snippet.trim().to_string());
+                        snippet.clear();
+                    }
+                    match name.as_str() {
+                        "code" => code_depth -= 1,
+                        _ => em_depth -= 1,
+                    }
+                }
+                _ => {}
+            },
+            Token::Text(text) => match &mut block {
+                Some(block) => block.push_str(text),
+                None => {
+                    if code_depth > 0 && em_depth > 0 {
+                        snippet.push_str(text);
+                    }
+                    paragraph.push_str(text);
+                }
+            },
+        }
+    }
+
+    let input = blocks
+        .iter()
+        .find(|(intro, _)| intro.contains("example"))
+        .or(blocks.first())
+        .map(|(_, block)| block.clone());
+    (input, answer)
+}
+
+/// Formats answers as one `part{P}: {answer}` line per part
+pub fn format_answers(answers: &[(u8, String)]) -> String {
+    answers
+        .iter()
+        .map(|(part, answer)| format!("part{part}: {answer}\n"))
+        .collect()
+}
+
+/// Parses the `part{P}: {answer}` lines written by [`format_answers`], skipping malformed ones
+pub fn parse_answers(answers: &str) -> Vec<(u8, String)> {
+    answers
+        .lines()
+        .filter_map(|l| {
+            let (part, answer) = l.split_once(':')?;
+            let part = part.trim().strip_prefix("part")?.parse().ok()?;
+            Some((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+fn fixture_dir(year: u16, day: u8) -> PathBuf {
+    fixtures_dir()
+        .join(year.to_string())
+        .join(format!("day{}", day))
+}
+
+/// Writes the given examples as fixtures of the given day, replacing any existing ones
+pub fn write_fixtures(year: u16, day: u8, examples: &[Example]) -> io::Result<PathBuf> {
+    let dir = fixture_dir(year, day);
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+
+    for (idx, example) in examples.iter().enumerate() {
+        let name = format!("example{}", idx + 1);
+        fs::write(dir.join(format!("{name}.txt")), &example.input)?;
+
+        fs::write(
+            dir.join(format!("{name}.answers")),
+            format_answers(&example.answers),
+        )?;
+    }
+    Ok(dir)
+}
+
+/// Loads the fixtures of the given day, sorted by name
+#[cfg(test)]
+pub fn load_fixtures(year: u16, day: u8) -> io::Result<Vec<(String, Example)>> {
+    let dir = fixture_dir(year, day);
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut fixtures = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let name = path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let input = fs::read_to_string(&path)?;
+        let answers = parse_answers(&fs::read_to_string(path.with_extension("answers"))?);
+        fixtures.push((name, Example { input, answers }));
+    }
+    fixtures.sort_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(fixtures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn extract_examples_shared_input() {
+        let html = indoc! {r#"
+            <article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+            <p>The numbers are <code><em>11</em></code> apart.</p>
+            <p>For example:</p>
+            <pre><code>3   4
+            4   3
+            </code></pre>
+            <p>In this example, the total distance is <code><em>11</em></code>.</p>
+            </article>
+            <article class="day-desc"><h2>--- Part Two ---</h2>
+            <p>So, for the example above, the similarity score is <em><code>31</code></em>.</p>
+            </article>
+        "#};
+        assert_eq!(
+            extract_examples(html),
+            vec![Example {
+                input: "3   4\n4   3\n".to_string(),
+                answers: vec![(1, "11".to_string()), (2, "31".to_string())],
+            }]
+        );
+    }
+
+    #[test]
+    fn extract_examples_separate_input() {
+        let html = indoc! {r#"
+            <article class="day-desc"><h2>--- Day 3: Mull It Over ---</h2>
+            <p>For example, consider the following section of corrupted memory:</p>
+            <pre><code>x<em>mul(2,4)</em>%</code></pre>
+            <p>Adding up the result produces <code><em>8</em></code>.</p>
+            </article>
+            <article class="day-desc"><h2>--- Part Two ---</h2>
+            <p>Here is a new example:</p>
+            <pre><code>x<em>mul(2,4)</em>don't()</code></pre>
+            <p>This time, the sum of the results is <code><em>8</em></code>.</p>
+            </article>
+        "#};
+        let examples = extract_examples(html);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input, "xmul(2,4)%");
+        assert_eq!(examples[1].input, "xmul(2,4)don't()");
+        assert_eq!(examples[1].answers, vec![(2, "8".to_string())]);
+    }
+}
@@ -1,11 +1,59 @@
 //! fetch.rs
 //!
-//! Allows to automatically fetch AoC puzzle inputs
+//! Allows to automatically fetch AoC puzzle inputs, descriptions, private leaderboards and the event calendar
 //!
+//! Every request goes through [`get`], which refuses to touch the network in offline mode
+//! (`--offline` or the `AOC_OFFLINE` env variable). Without the `fetch` feature, the offline mode is
+//! always enabled.
+//!
+
+use std::{
+    error::Error,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use super::{
+    env_enabled,
+    session::{active_profile, session_cookie},
+    AoCError,
+};
+
+pub const AOC_FQDN: &str = "https://adventofcode.com";
+
+const OFFLINE_VAR: &str = "AOC_OFFLINE";
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Enables the offline mode for the rest of the run
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Returns whether the offline mode is enabled, either explicitly or by the `AOC_OFFLINE` env variable
+pub fn is_offline() -> bool {
+    !cfg!(feature = "fetch") || OFFLINE.load(Ordering::Relaxed) || env_enabled(OFFLINE_VAR)
+}
+
+#[cfg(feature = "fetch")]
+async fn get(url: String, cookie: Option<String>) -> Result<String, Box<dyn Error>> {
+    if is_offline() {
+        return Err(AoCError::Offline.into());
+    }
 
-use std::{env, error::Error};
+    let client = reqwest::Client::new();
+    let mut req = client.get(url);
+    if let Some(cookie) = cookie {
+        req = req.header("Cookie", cookie);
+    }
+    let res = req.send().await?.error_for_status()?;
 
-const AOC_FQDN: &str = "https://adventofcode.com";
+    Ok(res.text().await?)
+}
+
+#[cfg(not(feature = "fetch"))]
+async fn get(_url: String, _cookie: Option<String>) -> Result<String, Box<dyn Error>> {
+    Err(AoCError::Offline.into())
+}
 
 /// Fetches the puzzle input corresponding to the given year and day parameters directly from the AoC Website
 ///
@@ -14,11 +62,54 @@ const AOC_FQDN: &str = "https://adventofcode.com";
 /// * `day` - The day
 pub async fn fetch_input(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
     let url = format!("{AOC_FQDN}/{year}/day/{day}/input");
+    get(url, Some(session_cookie()?)).await
+}
 
-    let cookie = env::var("AOC_SESSION")?;
+/// Fetches the HTML puzzle page corresponding to the given year and day parameters.
+/// The session cookie is sent if available, as part 2 is only shown to logged in users who solved part 1.
+///
+/// # Arguments
+/// * `year` - The year in the `YYYY` format
+/// * `day` - The day
+pub async fn fetch_description(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+    let url = format!("{AOC_FQDN}/{year}/day/{day}");
+    get(url, active_profile().cookie()).await
+}
 
-    let client = reqwest::Client::new();
-    let res = client.get(url).header("Cookie", cookie).send().await?;
+/// Fetches the JSON of the private leaderboard with the given id for the given year.
+/// AoC asks to not call this more often than every 15 minutes.
+///
+/// # Arguments
+/// * `year` - The year in the `YYYY` format
+/// * `id` - The id of the private leaderboard
+pub async fn fetch_leaderboard(year: u16, id: &str) -> Result<String, Box<dyn Error>> {
+    let url = format!("{AOC_FQDN}/{year}/leaderboard/private/view/{id}.json");
+    get(url, Some(session_cookie()?)).await
+}
 
-    Ok(res.text().await?)
+/// Fetches the HTML event calendar of the given year, which shows the stars of the logged in user
+///
+/// # Arguments
+/// * `year` - The year in the `YYYY` format
+pub async fn fetch_calendar(year: u16) -> Result<String, Box<dyn Error>> {
+    let url = format!("{AOC_FQDN}/{year}");
+    get(url, Some(session_cookie()?)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn offline_mode_blocks_requests() {
+        set_offline(true);
+        let res = get(format!("{AOC_FQDN}/2024"), None).await;
+        set_offline(false);
+
+        let err = res.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AoCError>(),
+            Some(AoCError::Offline)
+        ));
+    }
 }
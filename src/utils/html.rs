@@ -0,0 +1,220 @@
+//! html.rs
+//!
+//! Minimal HTML tokenizer, just enough to pick apart the AoC puzzle pages
+//!
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Open {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    Close(String),
+    Text(String),
+}
+
+impl Token {
+    pub fn is_open(&self, tag: &str) -> bool {
+        matches!(self, Self::Open { name, .. } if name == tag)
+    }
+
+    pub fn is_close(&self, tag: &str) -> bool {
+        matches!(self, Self::Close(name) if name == tag)
+    }
+
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        let Self::Open { attrs, .. } = self else {
+            return None;
+        };
+        attrs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Splits the given HTML into tags and (entity decoded) text nodes.
+/// Comments, doctypes and self-closing markers are skipped.
+pub fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(decode_entities(&rest[..start])));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(decode_entities(rest)));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim().to_lowercase()));
+            continue;
+        }
+        let tag = tag.trim_end_matches('/');
+        let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        tokens.push(Token::Open {
+            name: name.to_lowercase(),
+            attrs: parse_attrs(attrs),
+        });
+    }
+    tokens
+}
+
+fn parse_attrs(mut s: &str) -> Vec<(String, String)> {
+    let mut attrs = vec![];
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return attrs;
+        }
+        let key_end = s
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(s.len());
+        let key = s[..key_end].to_lowercase();
+        s = s[key_end..].trim_start();
+
+        let Some(value) = s.strip_prefix('=') else {
+            attrs.push((key, String::new()));
+            continue;
+        };
+        let value = value.trim_start();
+        let (value, remaining) = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value = &value[1..];
+                let end = value.find(quote).unwrap_or(value.len());
+                (&value[..end], value.get(end + 1..).unwrap_or(""))
+            }
+            _ => {
+                let end = value.find(char::is_whitespace).unwrap_or(value.len());
+                (&value[..end], &value[end..])
+            }
+        };
+        attrs.push((key, decode_entities(value)));
+        s = remaining;
+    }
+}
+
+pub fn decode_entities(s: &str) -> String {
+    if !s.contains('&') {
+        return s.to_string();
+    }
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Returns the token slices of every `<article class="day-desc">` on a puzzle page
+pub fn day_desc_articles(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut articles = vec![];
+    let mut idx = 0;
+    while idx < tokens.len() {
+        let t = &tokens[idx];
+        if !(t.is_open("article") && t.attr("class") == Some("day-desc")) {
+            idx += 1;
+            continue;
+        }
+        let start = idx + 1;
+        let mut depth = 1;
+        idx += 1;
+        while idx < tokens.len() {
+            if tokens[idx].is_open("article") {
+                depth += 1;
+            } else if tokens[idx].is_close("article") {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            idx += 1;
+        }
+        articles.push(&tokens[start..idx.min(tokens.len())]);
+        idx += 1;
+    }
+    articles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_tags_attrs_and_entities() {
+        let tokens = tokenize(r#"<p class="x">a &lt;b&gt; &amp; c<!-- skip --><br/></p>"#);
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Open {
+                    name: "p".to_string(),
+                    attrs: vec![("class".to_string(), "x".to_string())],
+                },
+                Token::Text("a <b> & c".to_string()),
+                Token::Open {
+                    name: "br".to_string(),
+                    attrs: vec![],
+                },
+                Token::Close("p".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn day_desc_articles_finds_both_parts() {
+        let tokens = tokenize(
+            r#"<main><article class="day-desc"><h2>A</h2></article><p>x</p><article class="day-desc"><h2>B</h2></article></main>"#,
+        );
+        let articles = day_desc_articles(&tokens);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[1][1], Token::Text("B".to_string()));
+    }
+}
@@ -0,0 +1,214 @@
+//! leaderboard.rs
+//!
+//! Fetches and caches AoC private leaderboards
+//!
+
+use std::{collections::HashMap, error::Error, fs, path::Path, time};
+
+use chrono::{DateTime, TimeZone};
+use serde::Deserialize;
+
+use super::{
+    cache::write_cache,
+    fetch::{fetch_leaderboard, is_offline},
+    session::{active_profile, whoami},
+    AoCError, Utc, AOC_MONTH, AOC_UTC_HOUR,
+};
+
+/// AoC asks to not request a private leaderboard more often than every 15 minutes
+const REFRESH_INTERVAL: time::Duration = time::Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    #[serde(default)]
+    pub completion_day_level: HashMap<u8, HashMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// Returns the number of stars obtained on the given day
+    pub fn stars_on(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, |d| d.len())
+    }
+
+    /// Returns the time it took to solve the given part, relative to the puzzle unlock
+    pub fn solve_time(&self, year: u16, day: u8, part: u8) -> Option<chrono::Duration> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        let solved = DateTime::from_timestamp(star.get_star_ts, 0)?;
+        Some(solved - unlock_time(year, day))
+    }
+}
+
+impl Leaderboard {
+    /// Returns the members ordered by local score, then by stars
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+}
+
+fn unlock_time(year: u16, day: u8) -> DateTime<chrono::Utc> {
+    chrono::Utc
+        .with_ymd_and_hms(year.into(), AOC_MONTH, day.into(), AOC_UTC_HOUR, 0, 0)
+        .unwrap()
+}
+
+/// Returns the private leaderboard with the given id for the given year.
+///
+/// The leaderboard is cached as `leaderboard{ID}.json` in the cache directory of the active profile and only
+/// fetched again once the cached copy is older than 15 minutes. In offline mode
+/// the cached copy is used regardless of its age.
+///
+/// Every request is recorded by touching `leaderboard{ID}.attempt`, so failed requests are not
+/// repeated within 15 minutes either.
+pub async fn get_leaderboard(year: u16, id: &str) -> Result<Leaderboard, Box<dyn Error>> {
+    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(AoCError::InvalidLeaderboardId.into());
+    }
+    let profile = active_profile();
+    let filename = profile
+        .cache_dir(year)
+        .join(format!("leaderboard{}.json", id));
+    let attempt = filename.with_extension("attempt");
+    let cached = fs::read_to_string(&filename).ok();
+    let can_fetch = profile.token.is_some() && !is_offline();
+
+    let json = match cached {
+        Some(cached) if !can_fetch || is_recent(&filename) => cached,
+        cached if can_fetch && is_recent(&attempt) => cached.ok_or(AoCError::TooManyRequests)?,
+        cached => {
+            if can_fetch {
+                write_cache(&attempt, Utc::now().to_rfc3339())?;
+            }
+            match fetch_leaderboard_checked(year, id).await {
+                Ok(json) => {
+                    write_cache(&filename, &json)?;
+                    json
+                }
+                Err(e) => cached.ok_or(e)?,
+            }
+        }
+    };
+
+    serde_json::from_str(&json).map_err(|_| AoCError::InvalidLeaderboard.into())
+}
+
+/// Whether the given file was written less than 15 minutes ago
+fn is_recent(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < REFRESH_INTERVAL)
+}
+
+async fn fetch_leaderboard_checked(year: u16, id: &str) -> Result<String, Box<dyn Error>> {
+    whoami(year).await?;
+    fetch_leaderboard(year, id).await
+}
+
+/// Formats a solve time as `HH:MM:SS`, prefixed by the whole days it took, if any
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let secs = duration.num_seconds().max(0);
+    let (days, secs) = (secs / 86400, secs % 86400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    );
+    match days {
+        0 => time,
+        _ => format!("{days}d {time}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{examples::fixtures_dir, test_dir::TestDir};
+
+    fn fixture() -> Leaderboard {
+        let json = fs::read_to_string(fixtures_dir().join("leaderboard.json")).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn leaderboard_ranking() {
+        let leaderboard = fixture();
+        let ranking: Vec<String> = leaderboard
+            .ranking()
+            .iter()
+            .map(|m| m.display_name())
+            .collect();
+        assert_eq!(ranking, vec!["alice", "(anonymous user #1002)", "carol"]);
+    }
+
+    #[test]
+    fn leaderboard_stars_and_times() {
+        let leaderboard = fixture();
+        let alice = &leaderboard.members["1001"];
+        assert_eq!(alice.stars_on(1), 2);
+        assert_eq!(alice.stars_on(2), 1);
+        assert_eq!(alice.stars_on(3), 0);
+        assert_eq!(
+            format_duration(alice.solve_time(2024, 1, 1).unwrap()),
+            "00:05:00"
+        );
+        assert_eq!(
+            format_duration(alice.solve_time(2024, 2, 1).unwrap()),
+            "00:30:00"
+        );
+        assert_eq!(alice.solve_time(2024, 2, 2), None);
+
+        let anonymous = &leaderboard.members["1002"];
+        assert_eq!(
+            format_duration(anonymous.solve_time(2024, 1, 2).unwrap()),
+            "1d 02:00:00"
+        );
+    }
+
+    #[tokio::test]
+    async fn leaderboard_ids_are_digits() {
+        for id in ["", "12a", "../42", "42/../../token"] {
+            assert!(matches!(
+                get_leaderboard(2024, id).await.unwrap_err().downcast_ref(),
+                Some(AoCError::InvalidLeaderboardId)
+            ));
+        }
+    }
+
+    #[test]
+    fn recent_files() {
+        let dir = TestDir::new("leaderboard");
+        let path = dir.join("leaderboard42.attempt");
+        assert!(!is_recent(&path));
+        write_cache(&path, "").unwrap();
+        assert!(is_recent(&path));
+    }
+}
@@ -1,53 +1,94 @@
+mod answer;
+mod answer_cache;
+mod aocd;
+mod batch;
+mod cache;
+mod compare;
+mod crypt;
 mod day;
+mod description;
+mod event;
+mod examples;
 mod fetch;
+mod html;
+mod leaderboard;
 #[cfg(test)]
 mod mock_time;
+mod params;
+mod parse;
+#[cfg(feature = "fetch")]
+mod prefetch;
+mod progress;
+mod project;
+mod session;
+#[cfg(test)]
+mod test_dir;
+pub mod trace;
 
-use std::{
-    env,
-    error::Error,
-    fmt,
-    fs::File,
-    io::{self, Read, Write},
-    time,
-};
+use std::{env, error::Error, fmt};
 
 #[cfg(not(test))]
 use chrono::Utc;
 #[cfg(test)]
 use mock_time::Utc;
 
+pub use answer::Answer;
+pub use answer_cache::{answer_key, cached_answers, set_no_cache, store_answers, AnswerKey};
+pub use batch::{run_input, run_inputs, InputRun, PartRun};
+pub use cache::migrate_flat_cache;
+use cache::{check_input, read_private, Integrity};
 use chrono::{Datelike, Timelike};
-use colored::{Colorize, CustomColor};
-pub use day::DaySolver;
+pub use compare::run_variants;
+pub use crypt::{cache_key, convert_cache};
+pub use day::{DaySolver, Registration, Solution};
+pub use description::{get_description, get_puzzle_page};
+pub use event::{event, Event};
+#[cfg(test)]
+pub use examples::load_fixtures;
+pub use examples::{extract_examples, write_fixtures};
 use fetch::fetch_input;
+use fetch::is_offline;
+pub use fetch::{fetch_calendar, set_offline};
+pub use leaderboard::{format_duration, get_leaderboard, Leaderboard, Member};
+pub use params::{
+    check_param_overrides, params_for, parse_param, set_param_overrides, Param, Params,
+};
+pub use parse::{
+    char_tokens, grid_size, normalize_input, parse_token, sections, split_once, strip_prefix,
+    SolveError,
+};
+#[cfg(feature = "fetch")]
+pub use prefetch::{prefetch_inputs, FetchStatus};
+pub use progress::{compare_progress, parse_calendar, DayProgress, Mismatch};
+pub use project::{project_dir, set_project_dir};
+pub use session::{active_profile, init_profile, parse_user, whoami, Layout};
+pub use trace::{capture, TraceEvent};
 
-use crate::{days::get_solver, AOC_YEAR};
+use crate::days::years;
 
+const YEAR_VAR: &str = "AOC_YEAR";
 const AOC_MONTH: u32 = 12;
 const AOC_UTC_HOUR: u32 = 5;
 
-const AOC_GRAY: CustomColor = CustomColor {
-    r: 105,
-    g: 105,
-    b: 105,
-};
-const AOC_BLUE: CustomColor = CustomColor {
-    r: 15,
-    g: 15,
-    b: 35,
-};
-const AOC_YELLOW: CustomColor = CustomColor {
-    r: 255,
-    g: 255,
-    b: 72,
-};
-const AOC_GREEN: CustomColor = CustomColor { r: 0, g: 176, b: 0 };
-
 #[derive(Debug, Clone)]
-enum AoCError {
+pub enum AoCError {
     InvalidDay,
     NoInput,
+    Unpublished,
+    NoDescription,
+    NoLeaderboard,
+    InvalidLeaderboardId,
+    InvalidLeaderboard,
+    TooManyRequests,
+    NoSession,
+    InvalidSession,
+    Offline,
+    MissingOfflineInput,
+    CorruptInput,
+    MissingKey,
+    DecryptionFailed,
+    UnknownParam(String, Vec<&'static str>),
+    InvalidParam(Param, i64),
 }
 
 impl fmt::Display for AoCError {
@@ -55,48 +96,150 @@ impl fmt::Display for AoCError {
         match self {
             Self::InvalidDay => write!(f, "Invalid Day"),
             Self::NoInput => write!(f, "Did not find any input for the selected day"),
+            Self::Unpublished => write!(f, "Unable to fetch unpublished puzzle. Please come back later!"),
+            Self::NoDescription => {
+                write!(f, "Did not find any description for the selected day")
+            }
+            Self::NoLeaderboard => write!(f, "No private leaderboard id given"),
+            Self::InvalidLeaderboardId => write!(f, "The leaderboard id must only consist of digits"),
+            Self::InvalidLeaderboard => write!(f, "Unable to parse the private leaderboard"),
+            Self::TooManyRequests => write!(
+                f,
+                "The leaderboard was requested less than 15 minutes ago, please try again later"
+            ),
+            Self::NoSession => write!(f, "No session token set for the active profile"),
+            Self::InvalidSession => write!(f, "The session token is invalid or expired"),
+            Self::Offline => write!(f, "Network access is disabled in offline mode"),
+            Self::MissingOfflineInput => {
+                write!(
+                    f,
+                    "The input is not cached and cannot be fetched in offline mode"
+                )
+            }
+            Self::CorruptInput => write!(f, "The input is empty or corrupted"),
+            Self::MissingKey => write!(
+                f,
+                "The cached file is encrypted, but neither AOC_CACHE_KEY nor AOC_CACHE_KEY_FILE is set"
+            ),
+            Self::DecryptionFailed => {
+                write!(f, "Unable to decrypt the cached file with the given key")
+            }
+            Self::UnknownParam(name, declared) if declared.is_empty() => {
+                write!(f, "Unknown parameter `{name}`, the solver has no parameters")
+            }
+            Self::UnknownParam(name, declared) => write!(
+                f,
+                "Unknown parameter `{name}`, expected one of: {}",
+                declared.join(", ")
+            ),
+            Self::InvalidParam(param, value) => write!(
+                f,
+                "Invalid value {value} of parameter `{}`, expected {} to {}",
+                param.name, param.min, param.max
+            ),
         }
     }
 }
 
 impl Error for AoCError {}
 
-async fn get_input(day: u8) -> Result<String, Box<dyn Error>> {
-    let filename = format!("inputs/day{}.txt", day);
-    if let io::Result::Ok(mut file) = File::open(&filename) {
-        let mut input = String::new();
-        let _ = file.read_to_string(&mut input);
-        return Ok(input);
+/// Returns the cached input of the given day of the active profile, without touching the network
+pub fn load_input(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+    let filename = active_profile().input_path(year, day);
+    let input = read_private(&filename)?.ok_or(AoCError::NoInput)?;
+    match check_input(&filename, &input) {
+        Integrity::Valid => Ok(input),
+        _ => Err(AoCError::CorruptInput.into()),
+    }
+}
+
+/// Returns the input of the given day of the active profile. Inputs which are not cached (or
+/// corrupted) are fetched and cached, unless the offline mode is enabled.
+pub async fn get_input(year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+    let profile = active_profile();
+    let filename = profile.input_path(year, day);
+    let mut corrupt = false;
+    if let Some(input) = read_private(&filename)? {
+        match check_input(&filename, &input) {
+            Integrity::Valid => return Ok(input),
+            _ => corrupt = true,
+        }
+    }
+    let no_input = |fallback: AoCError| match corrupt {
+        true => AoCError::CorruptInput,
+        false => fallback,
     };
 
-    if env::var("AOC_SESSION").is_err() {
-        println!("Fetching input for day {day} from AoC Website is not possible, as the AOC_SESSION env variable is not present. Either place it in `.env` or enable it temporarily in your shell session.");
-        println!(
-            "You can also grab the input yourself and place it under `inputs/day{}.txt`",
-            day
-        );
-        return Err(AoCError::NoInput.into());
+    if is_offline() {
+        return Err(no_input(AoCError::MissingOfflineInput).into());
     }
 
-    if !is_puzzle_available(day) {
-        println!("Unable to fetch unpublished puzzle. Please come back later!");
-        return Err(AoCError::NoInput.into());
+    if profile.token.is_none() {
+        return Err(no_input(AoCError::NoInput).into());
     }
 
-    let input = fetch_input(AOC_YEAR, day).await?;
-    let mut file = File::create(&filename)?;
-    file.write_all(input.as_bytes())?;
+    if !is_puzzle_available(year, day) {
+        return Err(no_input(AoCError::Unpublished).into());
+    }
+
+    whoami(year).await?;
+    let input = fetch_input(year, day).await?;
+    if input.trim().is_empty() {
+        return Err(AoCError::CorruptInput.into());
+    }
+    profile.write_input(year, day, &input)?;
     Ok(input)
 }
 
-fn is_puzzle_available(day: u8) -> bool {
+/// Checks that the given day is part of the event of the given year
+pub fn check_day(year: u16, day: u8) -> Result<(), AoCError> {
+    match event(year).has_day(day) {
+        true => Ok(()),
+        false => Err(AoCError::InvalidDay),
+    }
+}
+
+/// Returns whether the given env variable is set to a truthy value like `1` or `true`
+fn env_enabled(var: &str) -> bool {
+    env::var(var).is_ok_and(|v| {
+        matches!(
+            v.trim().to_lowercase().as_str(),
+            "1" | "true" | "yes" | "on"
+        )
+    })
+}
+
+/// The event to work on: the given year, else the `AOC_YEAR` env variable, else the latest year with
+/// solvers
+pub fn select_year(year: Option<u16>) -> u16 {
+    year.or_else(|| env::var(YEAR_VAR).ok()?.trim().parse().ok())
+        .or_else(|| years().last().copied())
+        .unwrap_or_else(latest_event)
+}
+
+/// The year of the latest event which has started
+fn latest_event() -> u16 {
     let now = Utc::now();
+    let year = now.year() as u16;
+    match now.month() < AOC_MONTH {
+        true => year - 1,
+        false => year,
+    }
+}
 
-    if now.year() < AOC_YEAR.into() {
+/// Returns whether the puzzle of the given day has been published
+pub fn is_puzzle_available(year: u16, day: u8) -> bool {
+    if !event(year).has_day(day) {
         return false;
     }
 
-    if now.year() > AOC_YEAR.into() {
+    let now = Utc::now();
+
+    if now.year() < year.into() {
+        return false;
+    }
+
+    if now.year() > year.into() {
         return true;
     }
 
@@ -119,94 +262,6 @@ fn is_puzzle_available(day: u8) -> bool {
     true
 }
 
-pub async fn solve_day(day: u8) -> Result<(), Box<dyn Error>> {
-    let Some(solver) = get_solver(day) else {
-        println!("({}) Day not solved yet!", "*".custom_color(AOC_GRAY));
-        println!();
-        return Ok(());
-    };
-
-    let input = get_input(day).await?;
-
-    let timer = time::Instant::now();
-    let p1 = solver.part1(&input);
-    let p1_time = timer.elapsed().as_micros();
-
-    display_part_result(1, p1, p1_time);
-
-    let timer = time::Instant::now();
-    let p2 = solver.part2(&input);
-    let p2_time = timer.elapsed().as_micros();
-
-    display_part_result(2, p2, p2_time);
-
-    println!();
-    Ok(())
-}
-
-pub fn get_day() -> Result<u8, Box<dyn Error>> {
-    print!("Enter day (leave empty to solve all days): ");
-    io::stdout().flush()?;
-    let mut day = String::new();
-    io::stdin().read_line(&mut day).unwrap();
-    day = day.replace("\n", "").replace("\r", "");
-    let day = day.parse()?;
-    if day > 25 {
-        return Err(AoCError::InvalidDay.into());
-    }
-    Ok(day)
-}
-
-fn display_part_result(part: u8, part_res: Option<String>, time: u128) {
-    let Some(part_res) = part_res else {
-        println!(
-            "({}) Part {}: Not solved yet",
-            "*".custom_color(AOC_GRAY),
-            part,
-        );
-        return;
-    };
-
-    println!(
-        "({}) Part {}: {} (took {} ms)",
-        "*".custom_color(AOC_YELLOW),
-        part,
-        part_res.custom_color(AOC_YELLOW),
-        (time as f64) / 1000.0
-    );
-}
-
-pub fn display_banner(message: &str, x_padding: usize, clear: bool) {
-    let x_padding = " ".repeat(x_padding).on_custom_color(AOC_BLUE);
-    let y_border = "*"
-        .repeat(message.len() + 4)
-        .custom_color(AOC_YELLOW)
-        .on_custom_color(AOC_BLUE);
-
-    // Clear terminal
-    if clear {
-        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-    }
-    println!(
-        "{x_padding}{y_border}{x_padding}",
-        x_padding = x_padding,
-        y_border = y_border
-    );
-    println!(
-        "{x_padding}{left_border}{message}{right_border}{x_padding}",
-        x_padding = x_padding,
-        left_border = "* ".custom_color(AOC_YELLOW).on_custom_color(AOC_BLUE),
-        right_border = " *".custom_color(AOC_YELLOW).on_custom_color(AOC_BLUE),
-        message = message.custom_color(AOC_GREEN).on_custom_color(AOC_BLUE),
-    );
-    println!(
-        "{x_padding}{y_border}{x_padding}",
-        x_padding = x_padding,
-        y_border = y_border
-    );
-    println!();
-}
-
 #[cfg(test)]
 mod tests {
     use chrono::TimeZone;
@@ -214,111 +269,122 @@ mod tests {
 
     use super::*;
 
+    const YEAR: u16 = 2024;
+
     #[test]
     fn is_puzzle_available_test_day1_unpublished_1_year_to_go() {
         let dt = chrono::Utc
-            .with_ymd_and_hms((AOC_YEAR - 1).into(), 12, 1, 5, 0, 0)
+            .with_ymd_and_hms((YEAR - 1).into(), 12, 1, 5, 0, 0)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
 
-        assert_eq!(res, false);
+        assert!(!res);
     }
 
     #[test]
     fn is_puzzle_available_test_day1_unpublished_1_month_to_go() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 11, 1, 5, 0, 0)
+            .with_ymd_and_hms(YEAR.into(), 11, 1, 5, 0, 0)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
 
-        assert_eq!(res, false);
+        assert!(!res);
     }
 
     #[test]
     fn is_puzzle_available_test_day1_unpublished_1_day_to_go() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 11, 30, 5, 0, 0)
+            .with_ymd_and_hms(YEAR.into(), 11, 30, 5, 0, 0)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
 
-        assert_eq!(res, false);
+        assert!(!res);
     }
 
     #[test]
     fn is_puzzle_available_test_day1_unpublished_1_hour_to_go() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 12, 1, 4, 0, 0)
+            .with_ymd_and_hms(YEAR.into(), 12, 1, 4, 0, 0)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
 
-        assert_eq!(res, false);
+        assert!(!res);
     }
 
     #[test]
     fn is_puzzle_available_test_day1_unpublished_1_sec_to_go() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 12, 1, 4, 59, 59)
+            .with_ymd_and_hms(YEAR.into(), 12, 1, 4, 59, 59)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
 
-        assert_eq!(res, false);
+        assert!(!res);
     }
 
     #[test]
     fn is_puzzle_available_test_day14_unpublished_1_sec_to_go() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 12, 14, 4, 59, 59)
+            .with_ymd_and_hms(YEAR.into(), 12, 14, 4, 59, 59)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(14);
+        let res = is_puzzle_available(YEAR, 14);
 
-        assert_eq!(res, false);
+        assert!(!res);
     }
 
     #[test]
     fn is_puzzle_available_test_day1_published_now() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 12, 1, 5, 0, 0)
+            .with_ymd_and_hms(YEAR.into(), 12, 1, 5, 0, 0)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
 
-        assert_eq!(res, true);
+        assert!(res);
     }
 
     #[test]
     fn is_puzzle_available_test_day14_published_now() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 12, 14, 5, 0, 0)
+            .with_ymd_and_hms(YEAR.into(), 12, 14, 5, 0, 0)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(14);
+        let res = is_puzzle_available(YEAR, 14);
 
-        assert_eq!(res, true);
+        assert!(res);
     }
 
     #[test]
     fn is_puzzle_available_test_day1_published_1_sec_ago() {
         let dt = chrono::Utc
-            .with_ymd_and_hms(AOC_YEAR.into(), 12, 1, 5, 0, 1)
+            .with_ymd_and_hms(YEAR.into(), 12, 1, 5, 0, 1)
             .unwrap();
         set_timestamp(dt.timestamp());
 
-        let res = is_puzzle_available(1);
+        let res = is_puzzle_available(YEAR, 1);
+
+        assert!(res);
+    }
+
+    #[test]
+    fn is_puzzle_available_test_day13_outside_12_day_event() {
+        let dt = chrono::Utc.with_ymd_and_hms(2025, 12, 20, 5, 0, 0).unwrap();
+        set_timestamp(dt.timestamp());
 
-        assert_eq!(res, true);
+        assert!(is_puzzle_available(2025, 12));
+        assert!(!is_puzzle_available(2025, 13));
     }
 }
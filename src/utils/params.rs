@@ -0,0 +1,216 @@
+//! params.rs
+//!
+//! Named puzzle parameters of the solvers, e.g. the grid size of day 14
+//!
+//! Every parameter has a default for the real input and a value for the puzzle examples. Both can be
+//! overridden, with the following precedence:
+//! 1. `--param name=value` on the command line, for every day declaring the parameter
+//! 2. The config file, `aoc.json` of the project directory or the file named by the `AOC_CONFIG` env
+//!    variable:
+//!    `{ "params": { "2024": { "14": { "width": 101, "height": 103 } } } }`
+//! 3. The sample value when running on examples, the default otherwise
+//!
+//! Overrides outside the valid range of a parameter are rejected before the solver is built.
+//!
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    error::Error,
+    fs, io,
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use serde::Deserialize;
+
+use super::{project_dir, AoCError, Registration};
+
+const CONFIG_VAR: &str = "AOC_CONFIG";
+
+static OVERRIDES: OnceLock<Vec<(String, i64)>> = OnceLock::new();
+
+/// A named parameter declared by a solver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value for the real puzzle input
+    pub default: i64,
+    /// The value for the puzzle examples
+    pub sample: i64,
+    /// The smallest valid value
+    pub min: i64,
+    /// The largest valid value
+    pub max: i64,
+}
+
+/// The parameter values a solver is built with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    sample: bool,
+    overrides: HashMap<String, i64>,
+}
+
+impl Params {
+    /// Parameters for the puzzle examples
+    pub fn sample() -> Self {
+        Self {
+            sample: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn with(mut self, name: &str, value: i64) -> Self {
+        self.overrides.insert(name.to_string(), value);
+        self
+    }
+
+    pub fn value(&self, param: &Param) -> i64 {
+        match self.overrides.get(param.name) {
+            Some(value) => *value,
+            None if self.sample => param.sample,
+            None => param.default,
+        }
+    }
+
+    /// Checks that every override is declared by the solver and within the range of its parameter
+    pub fn check(&self, declared: &[Param]) -> Result<(), AoCError> {
+        for (name, value) in &self.overrides {
+            let Some(param) = declared.iter().find(|p| p.name == name.as_str()) else {
+                return Err(AoCError::UnknownParam(
+                    name.clone(),
+                    declared.iter().map(|p| p.name).collect(),
+                ));
+            };
+            if !(param.min..=param.max).contains(value) {
+                return Err(AoCError::InvalidParam(*param, *value));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    #[serde(default)]
+    params: BTreeMap<u16, BTreeMap<u8, BTreeMap<String, i64>>>,
+}
+
+fn read_config() -> Result<Config, Box<dyn Error>> {
+    let path = env::var_os(CONFIG_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_dir().join("aoc.json"));
+    match fs::read_to_string(&path) {
+        Ok(json) => Ok(serde_json::from_str(&json)
+            .map_err(|e| format!("Invalid config file `{}`: {e}", path.display()))?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Parses a `name=value` command line override
+pub fn parse_param(arg: &str) -> Result<(String, i64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected `name=value`, found `{arg}`"))?;
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("expected an integer value, found `{value}`"))?;
+    Ok((name.trim().to_string(), value))
+}
+
+/// The parameters declared by the solvers of the given day, including its variants, or by every
+/// solver of the year
+fn declared_params(year: u16, day: Option<u8>) -> Vec<Param> {
+    inventory::iter::<Registration>()
+        .filter(|r| r.year == year && day.is_none_or(|day| r.day == day))
+        .flat_map(|r| r.params)
+        .copied()
+        .collect()
+}
+
+/// Checks that every command line override is declared by the solver of the selected day, or by
+/// any solver of the year when running every day
+pub fn check_param_overrides(year: u16, day: Option<u8>) -> Result<(), Box<dyn Error>> {
+    let mut overrides = Params::default();
+    for (name, value) in OVERRIDES.get().into_iter().flatten() {
+        overrides = overrides.with(name, *value);
+    }
+    Ok(overrides.check(&declared_params(year, day))?)
+}
+
+/// Sets the command line overrides for the rest of the run
+pub fn set_param_overrides(overrides: Vec<(String, i64)>) {
+    let _ = OVERRIDES.set(overrides);
+}
+
+/// The parameters for solving the real input of the given day, from the config file and the
+/// command line overrides the day declares
+pub fn params_for(year: u16, day: u8) -> Result<Params, Box<dyn Error>> {
+    let declared = declared_params(year, Some(day));
+    let mut params = Params::default();
+    let values = read_config()?
+        .params
+        .remove(&year)
+        .and_then(|mut days| days.remove(&day));
+    if let Some(values) = values {
+        for (name, value) in values {
+            params = params.with(&name, value);
+        }
+    }
+    for (name, value) in OVERRIDES.get().into_iter().flatten() {
+        if declared.iter().any(|p| p.name == name) {
+            params = params.with(name, *value);
+        }
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDTH: Param = Param {
+        name: "width",
+        default: 101,
+        sample: 11,
+        min: 1,
+        max: 1000,
+    };
+
+    #[test]
+    fn param_values() {
+        assert_eq!(Params::default().value(&WIDTH), 101);
+        assert_eq!(Params::sample().value(&WIDTH), 11);
+        assert_eq!(Params::sample().with("width", 5).value(&WIDTH), 5);
+
+        assert!(Params::default().with("width", 5).check(&[WIDTH]).is_ok());
+        assert!(matches!(
+            Params::default().with("depth", 5).check(&[WIDTH]),
+            Err(AoCError::UnknownParam(name, _)) if name == "depth"
+        ));
+        assert!(matches!(
+            Params::default().with("width", 0).check(&[WIDTH]),
+            Err(AoCError::InvalidParam(WIDTH, 0))
+        ));
+
+        assert_eq!(parse_param("width = 7"), Ok(("width".to_string(), 7)));
+        assert!(parse_param("width").is_err());
+        assert!(parse_param("width=x").is_err());
+    }
+
+    #[test]
+    fn declared_by_day_or_year() {
+        let names = |day| -> Vec<_> {
+            declared_params(2024, day)
+                .iter()
+                .map(|param| param.name)
+                .collect()
+        };
+        assert!(names(Some(1)).is_empty());
+        assert_eq!(names(Some(14)), ["width", "height"]);
+        assert!(names(None).contains(&"width"));
+        assert!(names(None).contains(&"offset"));
+    }
+}
@@ -0,0 +1,222 @@
+//! parse.rs
+//!
+//! Errors of the solvers and helpers to parse puzzle inputs without panicking
+//!
+//! Inputs are normalized before the solvers parse them, see [`normalize_input`].
+//!
+//! Parse errors point at the offending snippet of the input. Its line and column are derived from
+//! the position of the snippet within the input, so the helpers expect subslices of the input
+//! (e.g. from `lines` or `split`). Snippets from elsewhere are searched for in the input instead.
+//!
+
+use std::{borrow::Cow, error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed at the given 1-based line and column
+    Parse {
+        line: usize,
+        column: usize,
+        snippet: String,
+        /// The whole line holding the snippet
+        source_line: String,
+        expected: String,
+    },
+    /// The input is well-formed, but has no solution
+    NoSolution(String),
+}
+
+impl SolveError {
+    /// A parse error at the given snippet of the input
+    pub fn parse(input: &str, snippet: &str, expected: impl Into<String>) -> Self {
+        let offset = (snippet.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + snippet.len() <= input.len())
+            .or_else(|| input.find(snippet))
+            .unwrap_or(0);
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        Self::Parse {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: snippet.to_string(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                snippet,
+                expected,
+                ..
+            } if snippet.is_empty() => {
+                write!(
+                    f,
+                    "line {line}, column {column}: expected {expected}, found nothing"
+                )
+            }
+            Self::Parse {
+                line,
+                column,
+                snippet,
+                expected,
+                ..
+            } => write!(
+                f,
+                "line {line}, column {column}: expected {expected}, found `{snippet}`"
+            ),
+            Self::NoSolution(reason) => write!(f, "no solution: {reason}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Normalizes an input, so inputs edited on Windows behave the same as fetched ones: strips a byte
+/// order mark, turns `\r\n` and `\r` line endings into `\n` and trims trailing whitespace, including
+/// the final newline
+pub fn normalize_input(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input).trim_end();
+    match input.contains('\r') {
+        true => Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n")),
+        false => Cow::Borrowed(input),
+    }
+}
+
+/// Parses the given token of the input, e.g. a number
+pub fn parse_token<T: FromStr>(input: &str, token: &str, expected: &str) -> Result<T, SolveError> {
+    token
+        .trim()
+        .parse()
+        .map_err(|_| SolveError::parse(input, token, expected))
+}
+
+/// Splits the given part of the input at the first occurrence of the delimiter
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), SolveError> {
+    s.split_once(delimiter)
+        .ok_or_else(|| SolveError::parse(input, s, format!("`{delimiter}`")))
+}
+
+/// Strips the given prefix from a part of the input
+pub fn strip_prefix<'a>(input: &str, s: &'a str, prefix: &str) -> Result<&'a str, SolveError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| SolveError::parse(input, s, format!("`{prefix}`")))
+}
+
+/// Splits the input into its sections separated by blank lines and checks their number
+pub fn sections(input: &str, count: usize) -> Result<Vec<&str>, SolveError> {
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    if sections.len() != count {
+        let last = sections.last().copied().unwrap_or(input);
+        return Err(SolveError::parse(
+            input,
+            &last[last.len()..],
+            format!("{count} sections separated by a blank line"),
+        ));
+    }
+    Ok(sections)
+}
+
+/// Checks that the input is a non-empty rectangular grid and returns its width and height
+pub fn grid_size(input: &str) -> Result<(usize, usize), SolveError> {
+    let mut lines = input.lines();
+    let width = lines.next().map_or(0, |l| l.chars().count());
+    if width == 0 {
+        return Err(SolveError::parse(input, &input[..0], "a grid"));
+    }
+    for l in lines {
+        if l.chars().count() != width {
+            return Err(SolveError::parse(
+                input,
+                l,
+                format!("a grid row of width {width}"),
+            ));
+        }
+    }
+    Ok((width, input.lines().count()))
+}
+
+/// The characters of a line together with their snippet of the input
+pub fn char_tokens(line: &str) -> impl Iterator<Item = (char, &str)> {
+    line.char_indices()
+        .map(|(idx, c)| (c, &line[idx..idx + c.len_utf8()]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_location() {
+        let input = "3   4\n4   x3\n";
+        let token = input.lines().nth(1).unwrap().split("   ").nth(1).unwrap();
+        let err = parse_token::<isize>(input, token, "a number").unwrap_err();
+        assert_eq!(
+            err,
+            SolveError::Parse {
+                line: 2,
+                column: 5,
+                snippet: "x3".to_string(),
+                source_line: "4   x3".to_string(),
+                expected: "a number".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 5: expected a number, found `x3`"
+        );
+
+        let copied = "x3".to_string();
+        let err = SolveError::parse(input, &copied, "a number");
+        assert!(matches!(
+            err,
+            SolveError::Parse {
+                line: 2,
+                column: 5,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn grid_and_sections() {
+        assert_eq!(grid_size("ab\ncd\n"), Ok((2, 2)));
+        assert!(matches!(
+            grid_size("ab\nc\n"),
+            Err(SolveError::Parse { line: 2, .. })
+        ));
+        assert!(grid_size("").is_err());
+
+        assert_eq!(sections("a\n\nb", 2), Ok(vec!["a", "b"]));
+        assert_eq!(sections("a\r\n\r\nb", 2).ok(), None);
+        assert!(matches!(
+            sections("a\nb\n", 2),
+            Err(SolveError::Parse { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn normalized_input() {
+        assert_eq!(normalize_input("\u{feff}ab\r\ncd\r\n\r\n"), "ab\ncd");
+        assert_eq!(normalize_input("ab\rcd \n"), "ab\ncd");
+        assert!(matches!(
+            normalize_input("ab\ncd\n"),
+            Cow::Borrowed("ab\ncd")
+        ));
+    }
+}
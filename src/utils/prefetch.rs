@@ -0,0 +1,83 @@
+//! prefetch.rs
+//!
+//! Downloads all missing puzzle inputs at once, e.g. before going offline
+//!
+
+use std::{error::Error, sync::Arc};
+
+use tokio::{sync::Semaphore, task::JoinSet};
+
+use super::{
+    cache::is_valid_input, fetch::fetch_input, is_puzzle_available, session::active_profile,
+    session::whoami, AoCError,
+};
+
+/// Number of inputs downloaded at the same time
+const FETCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchStatus {
+    Cached,
+    Fetched,
+    Locked,
+    Failed(String),
+}
+
+/// Downloads the inputs of the given days of the given year which are unlocked but not cached yet (or corrupted),
+/// with at most [`FETCH_CONCURRENCY`] requests in flight
+pub async fn prefetch_inputs(
+    year: u16,
+    days: impl Iterator<Item = u8>,
+) -> Result<Vec<(u8, FetchStatus)>, Box<dyn Error>> {
+    let profile = active_profile();
+    let mut statuses = vec![];
+    let mut missing = vec![];
+    for day in days {
+        match is_valid_input(&profile.input_path(year, day)) {
+            Ok(true) => statuses.push((day, FetchStatus::Cached)),
+            // Unreadable inputs, e.g. encrypted ones without cache key, are never replaced
+            Err(e) => statuses.push((day, FetchStatus::Failed(e.to_string()))),
+            Ok(false) if !is_puzzle_available(year, day) => {
+                statuses.push((day, FetchStatus::Locked))
+            }
+            Ok(false) => missing.push(day),
+        }
+    }
+
+    if !missing.is_empty() {
+        if profile.token.is_none() {
+            return Err(AoCError::NoSession.into());
+        }
+        whoami(year).await?;
+    }
+
+    let semaphore = Arc::new(Semaphore::new(FETCH_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+    for day in missing {
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire().await;
+            let status = match download_input(year, day).await {
+                Ok(()) => FetchStatus::Fetched,
+                Err(e) => FetchStatus::Failed(e),
+            };
+            (day, status)
+        });
+    }
+    while let Some(res) = tasks.join_next().await {
+        statuses.push(res?);
+    }
+
+    statuses.sort_by_key(|(day, _)| *day);
+    Ok(statuses)
+}
+
+async fn download_input(year: u16, day: u8) -> Result<(), String> {
+    let input = fetch_input(year, day).await.map_err(|e| e.to_string())?;
+    if input.trim().is_empty() {
+        return Err(AoCError::CorruptInput.to_string());
+    }
+    active_profile()
+        .write_input(year, day, &input)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,123 @@
+//! progress.rs
+//!
+//! Pulls the personal star progress from the AoC event calendar and compares
+//! it with the locally available solvers
+//!
+
+use std::collections::BTreeMap;
+
+use super::html::tokenize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mismatch {
+    /// Stars were collected on the site, but there is no solver for the day
+    MissingCode,
+    /// There is a solver for the day, but not all of its answers were submitted
+    Unsubmitted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: u8,
+    pub stars: u8,
+    pub has_solver: bool,
+    pub mismatch: Option<Mismatch>,
+}
+
+/// Returns the stars per unlocked day of the event calendar page.
+/// Locked days are not part of the result.
+pub fn parse_calendar(html: &str) -> BTreeMap<u8, u8> {
+    let mut stars = BTreeMap::new();
+    for token in tokenize(html) {
+        if !token.is_open("a") {
+            continue;
+        }
+        let Some(classes) = token.attr("class") else {
+            continue;
+        };
+        let mut day = None;
+        let mut count = 0;
+        for class in classes.split_whitespace() {
+            match class {
+                "calendar-verycomplete" => count = 2,
+                "calendar-complete" => count = 1,
+                _ => {
+                    if let Some(d) = class.strip_prefix("calendar-day") {
+                        day = d.parse().ok();
+                    }
+                }
+            }
+        }
+        if let Some(day) = day {
+            stars.insert(day, count);
+        }
+    }
+    stars
+}
+
+/// Compares the stars of the site with the locally available solvers, given the number of parts
+/// of the solver per day. The free star of a single-part day is not expected to be submitted.
+pub fn compare_progress(
+    stars: &BTreeMap<u8, u8>,
+    days: impl Iterator<Item = u8>,
+    solver_parts: impl Fn(u8) -> Option<u8>,
+) -> Vec<DayProgress> {
+    days.map(|day| {
+        let stars = stars.get(&day).copied().unwrap_or(0);
+        let parts = solver_parts(day);
+        let mismatch = match (stars, parts) {
+            (1.., None) => Some(Mismatch::MissingCode),
+            (stars, Some(parts)) if stars < parts => Some(Mismatch::Unsubmitted),
+            _ => None,
+        };
+        DayProgress {
+            day,
+            stars,
+            has_solver: parts.is_some(),
+            mismatch,
+        }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::utils::examples::fixtures_dir;
+
+    fn fixture() -> String {
+        fs::read_to_string(fixtures_dir().join("calendar.html")).unwrap()
+    }
+
+    #[test]
+    fn parse_calendar_stars() {
+        let stars = parse_calendar(&fixture());
+        assert_eq!(
+            stars.into_iter().collect::<Vec<_>>(),
+            vec![(1, 2), (2, 2), (3, 1), (4, 0)]
+        );
+    }
+
+    #[test]
+    fn compare_progress_flags_mismatches() {
+        let stars = parse_calendar(&fixture());
+        let progress = compare_progress(&stars, 1..=5, |day| match day {
+            1 | 4 => Some(2),
+            3 => Some(1),
+            _ => None,
+        });
+        let mismatches: Vec<_> = progress.iter().map(|p| (p.day, p.mismatch)).collect();
+        assert_eq!(
+            mismatches,
+            vec![
+                (1, None),
+                (2, Some(Mismatch::MissingCode)),
+                (3, None),
+                (4, Some(Mismatch::Unsubmitted)),
+                (5, None),
+            ]
+        );
+    }
+}
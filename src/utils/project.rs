@@ -0,0 +1,25 @@
+//! project.rs
+//!
+//! The project directory the default locations are relative to: the input cache `inputs/`, the
+//! config file `aoc.json` and the example fixtures `fixtures/`
+//!
+//! It defaults to the working directory, so crates depending on this library keep their files in
+//! their own project. The CLI sets it to the directory of this crate.
+//!
+
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+static PROJECT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Sets the project directory for the rest of the run. Has no effect once it has been used.
+pub fn set_project_dir(dir: PathBuf) {
+    let _ = PROJECT_DIR.set(dir);
+}
+
+/// The project directory, the working directory unless set with [`set_project_dir`]
+pub fn project_dir() -> &'static Path {
+    PROJECT_DIR.get_or_init(PathBuf::new)
+}
@@ -0,0 +1,317 @@
+//! session.rs
+//!
+//! Named session profiles, each with its own token and input cache directory
+//!
+//! The default profile reads its token from `AOC_SESSION` and caches under
+//! `{root}/{year}/`. A named profile `alt` reads `AOC_SESSION_ALT` and caches under
+//! `{root}/alt/{year}/`. The active profile is picked with `--profile` or `AOC_PROFILE`.
+//!
+//! The cache root defaults to the `inputs/` directory of the project (see
+//! [`super::project`]) and can be
+//! changed with `--cache-dir` or `AOC_CACHE_DIR`.
+//!
+//! With the `aocd` layout (`--layout aocd` or `AOC_LAYOUT=aocd`), a profile
+//! without token env variable uses the token of `aocd` and inputs are shared
+//! with its cache once aocd knows the user id of the token, see [`super::aocd`]. Inputs are written into that cache the
+//! way aocd reads them: never encrypted and without metadata record. `login` stores a token for aocd and this crate.
+//!
+
+use std::{
+    env,
+    error::Error,
+    io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use strum_macros::{EnumString, VariantNames};
+
+use super::{
+    aocd::AocdDirs,
+    cache::{write_cache, write_input},
+    fetch::fetch_calendar,
+    html::{tokenize, Token},
+    project_dir, AoCError,
+};
+
+const SESSION_VAR: &str = "AOC_SESSION";
+const PROFILE_VAR: &str = "AOC_PROFILE";
+const LAYOUT_VAR: &str = "AOC_LAYOUT";
+const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+static PROFILE: OnceLock<Profile> = OnceLock::new();
+static USER: OnceLock<String> = OnceLock::new();
+
+/// Where the token is looked up and where inputs are cached
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Layout {
+    /// Tokens from env variables, inputs in the cache directory
+    #[default]
+    Local,
+    /// Falls back to the token of `aocd` and shares its input cache
+    Aocd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Profile {
+    pub name: Option<String>,
+    pub token: Option<String>,
+    pub aocd: Option<AocdDirs>,
+    pub cache_root: PathBuf,
+}
+
+impl Profile {
+    pub fn new(name: Option<String>, layout: Layout, cache_root: PathBuf) -> Self {
+        let name = name.filter(|n| !n.is_empty());
+        let aocd = (layout == Layout::Aocd).then(AocdDirs::from_env);
+        let token = env::var(session_var(name.as_deref()))
+            .ok()
+            .map(|t| normalize_token(&t))
+            .filter(|t| !t.is_empty())
+            .or_else(|| aocd.as_ref()?.read_token(name.as_deref()));
+        Self {
+            name,
+            token,
+            aocd,
+            cache_root,
+        }
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("default")
+    }
+
+    /// The env variable holding the token of this profile
+    pub fn session_var(&self) -> String {
+        session_var(self.name.as_deref())
+    }
+
+    /// The directory holding the cached files of this profile for the given year
+    pub fn cache_dir(&self, year: u16) -> PathBuf {
+        profile_dir(&self.cache_root, self.name.as_deref()).join(year.to_string())
+    }
+
+    /// The file the input of the given day is cached in
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.aocd_input_path(year, day)
+            .unwrap_or_else(|| self.cache_dir(year).join(format!("day{:02}.txt", day)))
+    }
+
+    /// The file aocd caches the input of the given day in, if the input is shared with aocd
+    fn aocd_input_path(&self, year: u16, day: u8) -> Option<PathBuf> {
+        self.aocd
+            .as_ref()?
+            .input_path(self.token.as_ref()?, year, day)
+    }
+
+    /// Caches the input of the given day. Inputs shared with the cache of aocd are stored as plain
+    /// text only, so aocd can still read them.
+    pub fn write_input(&self, year: u16, day: u8, input: &str) -> io::Result<()> {
+        match self.aocd_input_path(year, day) {
+            Some(path) => write_cache(&path, input),
+            None => write_input(&self.input_path(year, day), input, self.display_name()),
+        }
+    }
+
+    /// The directory holding the inputs of several people for the given day, e.g. `day09/alice.txt`
+    pub fn input_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir(year).join(format!("day{:02}", day))
+    }
+
+    /// The file a cached page of the given day is stored in, e.g. `html` or `md`
+    pub fn day_path(&self, year: u16, day: u8, extension: &str) -> PathBuf {
+        self.cache_dir(year)
+            .join(format!("day{:02}.{}", day, extension))
+    }
+
+    /// The `Cookie` header value for this profile
+    pub fn cookie(&self) -> Option<String> {
+        self.token.as_ref().map(|t| format!("session={t}"))
+    }
+}
+
+/// The directory holding the cached files of the given profile
+pub fn profile_dir(cache_root: &Path, name: Option<&str>) -> PathBuf {
+    match name {
+        Some(name) => cache_root.join(name),
+        None => cache_root.to_path_buf(),
+    }
+}
+
+fn session_var(name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{SESSION_VAR}_{}", name.to_uppercase().replace('-', "_")),
+        None => SESSION_VAR.to_string(),
+    }
+}
+
+/// Accepts a token with or without the `session=` prefix and returns the bare value
+pub fn normalize_token(token: &str) -> String {
+    let token = token.trim().trim_matches('"');
+    token
+        .strip_prefix("session=")
+        .unwrap_or(token)
+        .trim()
+        .to_string()
+}
+
+/// Selects the active profile, layout and cache root. Falls back to `AOC_PROFILE`,
+/// `AOC_LAYOUT` and `AOC_CACHE_DIR`, then to the default profile with the local layout.
+/// Has no effect once the active profile has been used.
+pub fn init_profile(name: Option<String>, layout: Option<Layout>, cache_root: Option<PathBuf>) {
+    let name = name.or_else(|| env::var(PROFILE_VAR).ok());
+    let layout = layout.unwrap_or_else(env_layout);
+    let cache_root = cache_root.unwrap_or_else(env_cache_root);
+    let _ = PROFILE.set(Profile::new(name, layout, cache_root));
+}
+
+pub fn active_profile() -> &'static Profile {
+    PROFILE.get_or_init(|| Profile::new(env::var(PROFILE_VAR).ok(), env_layout(), env_cache_root()))
+}
+
+fn env_cache_root() -> PathBuf {
+    env::var_os(CACHE_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_dir().join("inputs"))
+}
+
+fn env_layout() -> Layout {
+    env::var(LAYOUT_VAR)
+        .ok()
+        .and_then(|l| l.trim().parse().ok())
+        .unwrap_or_default()
+}
+
+/// Returns the `Cookie` header value of the active profile
+pub fn session_cookie() -> Result<String, AoCError> {
+    active_profile().cookie().ok_or(AoCError::NoSession)
+}
+
+/// Returns the AoC user name the page was rendered for, `None` if logged out
+pub fn parse_user(html: &str) -> Option<String> {
+    let tokens = tokenize(html);
+    let idx = tokens
+        .iter()
+        .position(|t| t.is_open("div") && t.attr("class") == Some("user"))?;
+    let name = tokens[idx + 1..]
+        .iter()
+        .take_while(|t| !t.is_open("span") && !t.is_close("div"))
+        .filter_map(|t| match t {
+            Token::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect::<String>();
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Confirms that the token of the active profile is valid and returns the user it belongs to.
+/// The check is done once per run, on the calendar of the given year.
+pub async fn whoami(year: u16) -> Result<String, Box<dyn Error>> {
+    if let Some(user) = USER.get() {
+        return Ok(user.clone());
+    }
+    let page = fetch_calendar(year).await?;
+    let user = parse_user(&page).ok_or(AoCError::InvalidSession)?;
+    Ok(USER.get_or_init(|| user).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::utils::{examples::fixtures_dir, test_dir::TestDir};
+
+    const YEAR: u16 = 2024;
+
+    #[test]
+    fn normalize_token_prefix() {
+        assert_eq!(normalize_token("session=53616c74"), "53616c74");
+        assert_eq!(normalize_token(" 53616c74\n"), "53616c74");
+        assert_eq!(normalize_token("\"session=53616c74\""), "53616c74");
+    }
+
+    #[test]
+    fn parse_layouts() {
+        assert_eq!("local".parse(), Ok(Layout::Local));
+        assert_eq!("AOCD".parse(), Ok(Layout::Aocd));
+        assert!("flat".parse::<Layout>().is_err());
+    }
+
+    #[test]
+    fn profile_paths_and_vars() {
+        let default = Profile {
+            name: None,
+            token: Some("abc".to_string()),
+            aocd: None,
+            cache_root: PathBuf::from("/cache"),
+        };
+        assert_eq!(default.session_var(), "AOC_SESSION");
+        assert_eq!(default.cache_dir(YEAR), PathBuf::from("/cache/2024"));
+        assert_eq!(default.cookie().unwrap(), "session=abc");
+        assert_eq!(
+            default.input_path(YEAR, 3),
+            PathBuf::from("/cache/2024/day03.txt")
+        );
+        assert_eq!(
+            default.day_path(YEAR, 12, "md"),
+            PathBuf::from("/cache/2024/day12.md")
+        );
+
+        let alt = Profile {
+            name: Some("alt-2".to_string()),
+            token: None,
+            aocd: None,
+            cache_root: PathBuf::from("/cache"),
+        };
+        assert_eq!(alt.session_var(), "AOC_SESSION_ALT_2");
+        assert_eq!(alt.cache_dir(2015), PathBuf::from("/cache/alt-2/2015"));
+        assert_eq!(alt.cookie(), None);
+
+        let aocd = Profile {
+            aocd: Some(AocdDirs {
+                config: PathBuf::from("/nonexistent/aocd"),
+                data: PathBuf::from("/nonexistent/aocd"),
+            }),
+            ..default
+        };
+        assert_eq!(
+            aocd.input_path(YEAR, 3),
+            PathBuf::from("/cache/2024/day03.txt")
+        );
+    }
+
+    #[test]
+    fn aocd_inputs_stay_plain() {
+        let dir = TestDir::new("session_aocd");
+        let aocd = Profile {
+            name: None,
+            token: Some("abc".to_string()),
+            aocd: Some(AocdDirs {
+                config: dir.to_path_buf(),
+                data: dir.to_path_buf(),
+            }),
+            cache_root: dir.join("cache"),
+        };
+        write_cache(&dir.join("token2id.json"), r#"{"abc": "github.alice.123"}"#).unwrap();
+        aocd.write_input(YEAR, 3, "1 2\n").unwrap();
+        let user_dir = dir.join("github.alice.123");
+        assert_eq!(
+            fs::read_to_string(user_dir.join("2024_03_input.txt")).unwrap(),
+            "1 2\n"
+        );
+        assert_eq!(fs::read_dir(user_dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn parse_user_logged_in_and_out() {
+        let page = fs::read_to_string(fixtures_dir().join("calendar.html")).unwrap();
+        assert_eq!(parse_user(&page).as_deref(), Some("alice"));
+        assert_eq!(
+            parse_user("<header><a href=\"/auth/login\">[Log In]</a></header>"),
+            None
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! trace.rs
+//!
+//! Structured events emitted by the solvers to explain how an answer came together, rendered by
+//! `--explain` instead of `println!`s littered into the days
+//!
+//! Solvers emit intermediate values, per-item contributions and grid snapshots:
+//! ```ignore
+//! trace::value("moved files", || moved);
+//! trace::contribution(|| (format!("file {id}"), id * start));
+//! trace::grid("disk", || render(&filesystem));
+//! ```
+//! The events are only built while the runner captures them: outside of a capture every emit is a
+//! single atomic load, and the closures are never called.
+//!
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use serde::Serialize;
+
+/// The number of captures running on any thread, the fast path of every emit
+static TRACING: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static EVENTS: RefCell<Option<Vec<TraceEvent>>> = const { RefCell::new(None) };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TraceEvent {
+    /// An intermediate value
+    Value { label: &'static str, value: String },
+    /// The contribution of a single item to the answer
+    Contribution { item: String, amount: String },
+    /// A snapshot of a grid, one string per row
+    Grid {
+        label: &'static str,
+        rows: Vec<String>,
+    },
+}
+
+#[inline]
+fn emit(event: impl FnOnce() -> TraceEvent) {
+    if TRACING.load(Ordering::Relaxed) == 0 {
+        return;
+    }
+    EVENTS.with_borrow_mut(|events| {
+        if let Some(events) = events {
+            events.push(event());
+        }
+    });
+}
+
+/// Emits an intermediate value
+#[inline]
+pub fn value<T: Display>(label: &'static str, value: impl FnOnce() -> T) {
+    emit(|| TraceEvent::Value {
+        label,
+        value: value().to_string(),
+    });
+}
+
+/// Emits the contribution of a single item to the answer
+#[inline]
+pub fn contribution<I: Display, A: Display>(contribution: impl FnOnce() -> (I, A)) {
+    emit(|| {
+        let (item, amount) = contribution();
+        TraceEvent::Contribution {
+            item: item.to_string(),
+            amount: amount.to_string(),
+        }
+    });
+}
+
+/// Emits a snapshot of a grid, rendered one line per row
+#[inline]
+pub fn grid<T: Display>(label: &'static str, grid: impl FnOnce() -> T) {
+    emit(|| TraceEvent::Grid {
+        label,
+        rows: grid().to_string().lines().map(str::to_string).collect(),
+    });
+}
+
+/// Ends a capture, even when the captured step panics
+struct Capturing;
+
+impl Drop for Capturing {
+    fn drop(&mut self) {
+        TRACING.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Runs `f` while capturing the events emitted on this thread
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Vec<TraceEvent>) {
+    let outer = EVENTS.replace(Some(vec![]));
+    TRACING.fetch_add(1, Ordering::Relaxed);
+    let capturing = Capturing;
+    let res = f();
+    drop(capturing);
+    let events = EVENTS.replace(outer).unwrap_or_default();
+    (res, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captured_events() {
+        value("ignored", || 1);
+        let (res, events) = capture(|| {
+            value("sum", || 3);
+            contribution(|| ("a", 1));
+            grid("map", || "#.\n.#");
+            42
+        });
+        assert_eq!(res, 42);
+        assert_eq!(
+            events,
+            [
+                TraceEvent::Value {
+                    label: "sum",
+                    value: "3".to_string()
+                },
+                TraceEvent::Contribution {
+                    item: "a".to_string(),
+                    amount: "1".to_string()
+                },
+                TraceEvent::Grid {
+                    label: "map",
+                    rows: vec!["#.".to_string(), ".#".to_string()]
+                },
+            ]
+        );
+
+        let mut called = false;
+        value("outside", || {
+            called = true;
+            0
+        });
+        assert!(!called);
+    }
+}